@@ -0,0 +1,168 @@
+use iced::{
+    widget::{pick_list, text, Column, Container, Row},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+use iced_aw::{
+    combo_box::{Matcher, Presentation},
+    ComboBox,
+};
+
+fn main() -> iced::Result {
+    ComboBoxExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    QueryChanged(String),
+    CountrySelected((usize, String)),
+    MatcherSelected(MatcherChoice),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatcherChoice {
+    Prefix,
+    Substring,
+    Fuzzy,
+}
+
+impl MatcherChoice {
+    const ALL: [MatcherChoice; 3] = [Self::Prefix, Self::Substring, Self::Fuzzy];
+
+    fn matcher(self) -> Matcher {
+        match self {
+            Self::Prefix => Matcher::Prefix,
+            Self::Substring => Matcher::Substring,
+            Self::Fuzzy => Matcher::Fuzzy,
+        }
+    }
+}
+
+impl std::fmt::Display for MatcherChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Prefix => "Prefix",
+                Self::Substring => "Substring",
+                Self::Fuzzy => "Fuzzy",
+            }
+        )
+    }
+}
+
+struct ComboBoxExample {
+    countries: Vec<String>,
+    query: String,
+    selected: Option<String>,
+    matcher: MatcherChoice,
+}
+
+impl Sandbox for ComboBoxExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let countries = [
+            "Argentina",
+            "Australia",
+            "Austria",
+            "Belgium",
+            "Brazil",
+            "Canada",
+            "Chile",
+            "Denmark",
+            "Finland",
+            "France",
+            "Germany",
+            "Iceland",
+            "Ireland",
+            "Japan",
+            "Netherlands",
+            "New Zealand",
+            "Norway",
+            "Portugal",
+            "Spain",
+            "Sweden",
+            "Switzerland",
+            "United Kingdom",
+            "United States",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+        Self {
+            countries,
+            query: String::new(),
+            selected: None,
+            matcher: MatcherChoice::Prefix,
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("ComboBox example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::QueryChanged(query) => self.query = query,
+            Message::CountrySelected((_, country)) => {
+                self.query = country.clone();
+                self.selected = Some(country);
+            }
+            Message::MatcherSelected(matcher) => self.matcher = matcher,
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let dropdown = ComboBox::new(
+            &self.countries[..],
+            "Search a country...",
+            &self.query,
+            Message::QueryChanged,
+            Message::CountrySelected,
+        )
+        .matcher(self.matcher.matcher())
+        .width(Length::Fixed(250.0));
+
+        let inline = ComboBox::new(
+            &self.countries[..],
+            "Filter the list below...",
+            &self.query,
+            Message::QueryChanged,
+            Message::CountrySelected,
+        )
+        .matcher(self.matcher.matcher())
+        .presentation(Presentation::Inline)
+        .max_height(150.0)
+        .width(Length::Fixed(250.0));
+
+        let content = Column::new()
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .align_items(Alignment::Center)
+                    .push(text("Matcher:"))
+                    .push(pick_list(
+                        &MatcherChoice::ALL[..],
+                        Some(self.matcher),
+                        Message::MatcherSelected,
+                    )),
+            )
+            .push(dropdown)
+            .push(text(format!(
+                "Selected: {}",
+                self.selected.as_deref().unwrap_or("none")
+            )))
+            .push(inline);
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .padding(40)
+            .into()
+    }
+}
@@ -3,7 +3,10 @@ use iced::{
     Alignment, Element, Sandbox, Settings,
 };
 
-use iced_aw::ContextMenu;
+use iced_aw::{
+    menu::{ItemHeight, ItemWidth, MenuItem},
+    ContextMenu, MenuTree,
+};
 
 fn main() -> iced::Result {
     ContextMenuExample::run(Settings::default())
@@ -59,7 +62,7 @@ impl Sandbox for ContextMenuExample {
                 ))),
         );
 
-        ContextMenu::new(underlay, || {
+        let context_menu = ContextMenu::new(underlay, || {
             column(vec![
                 iced::widget::button("Choice 1")
                     .on_press(Message::Choice1)
@@ -75,7 +78,30 @@ impl Sandbox for ContextMenuExample {
                     .into(),
             ])
             .into()
-        })
-        .into()
+        });
+
+        // a context menu with nested submenus
+        let menu = ContextMenu::with_menu(
+            Button::new(Text::new("right click me for a menu!")).on_press(Message::ButtonClicked),
+            vec![
+                MenuItem::action("Choice 1", Message::Choice1).into(),
+                MenuItem::action("Choice 2", Message::Choice2).into(),
+                MenuItem::separator().into(),
+                MenuTree::with_children(
+                    MenuItem::submenu("More"),
+                    vec![
+                        MenuItem::action("Choice 3", Message::Choice3),
+                        MenuItem::action("Choice 4", Message::Choice4),
+                    ],
+                ),
+            ],
+        )
+        .item_width(ItemWidth::Uniform(160))
+        .item_height(ItemHeight::Uniform(28));
+
+        column(vec![context_menu.into(), menu.into()])
+            .spacing(20)
+            .padding(20)
+            .into()
     }
 }
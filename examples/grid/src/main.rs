@@ -1,10 +1,11 @@
 use iced::{
+    alignment::Horizontal,
     theme,
     widget::{Button, Column, Container, Scrollable, Text},
     Alignment, Color, Element, Length, Sandbox, Settings,
 };
 
-use iced_aw::grid;
+use iced_aw::{grid, GridItem};
 
 // Number of columns for the grid
 const COLUMNS: usize = 2;
@@ -42,12 +43,21 @@ impl Sandbox for GridExample {
     }
 
     fn view(&self) -> Element<'_, self::Message> {
-        // Creates a grid with two columns
+        // Creates a grid with two columns and a title spanning both of them
         let mut grid = grid!(
             Text::new("Column 1").style(theme::Text::Color(Color::from_rgb8(255, 0, 0))),
             Text::new("Column 2").style(theme::Text::Color(Color::from_rgb8(255, 0, 0))),
         )
-        .strategy(iced_aw::Strategy::Columns(2));
+        .strategy(iced_aw::Strategy::Columns(2))
+        .column_widths(vec![Length::Fixed(200.0), Length::Shrink])
+        .column_alignments(vec![Horizontal::Right, Horizontal::Left])
+        .column_spacing(20.0)
+        .row_spacing(5.0)
+        .push_item(
+            GridItem::new(Text::new("Elements").size(24))
+                .position(0, 0)
+                .column_span(2),
+        );
 
         // Add elements to the grid
         for i in 0..self.element_index {
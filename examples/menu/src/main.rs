@@ -5,9 +5,14 @@ use iced::widget::{
 };
 use iced::{alignment, theme, Application, Color, Element, Length};
 
-use iced_aw::menu::{menu_tree::MenuTree, CloseCondition, ItemHeight, ItemWidth, PathHighlight};
+use iced::keyboard::{KeyCode, Modifiers};
+use iced_aw::menu::{
+    menu_bar, menu_tree::MenuTree, CloseCondition, ItemHeight, ItemWidth, MenuItem, Orientation,
+    PathHighlight, Shortcut,
+};
 use iced_aw::quad;
 use iced_aw::{helpers::menu_tree, menu_bar, menu_tree};
+use std::time::Duration;
 
 pub fn main() -> iced::Result {
     App::run(iced::Settings {
@@ -25,9 +30,10 @@ pub fn main() -> iced::Result {
 enum SizeOption {
     Uniform,
     Static,
+    Flex,
 }
 impl SizeOption {
-    const ALL: [SizeOption; 2] = [SizeOption::Uniform, SizeOption::Static];
+    const ALL: [SizeOption; 3] = [SizeOption::Uniform, SizeOption::Static, SizeOption::Flex];
 }
 impl std::fmt::Display for SizeOption {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -37,6 +43,7 @@ impl std::fmt::Display for SizeOption {
             match self {
                 Self::Uniform => "Uniform",
                 Self::Static => "Static",
+                Self::Flex => "Flex",
             }
         )
     }
@@ -54,6 +61,8 @@ enum Message {
     ThemeChange(bool),
     TextChange(String),
     SizeOption(SizeOption),
+    VerticalChange(bool),
+    OpenMenu,
 }
 
 struct App {
@@ -67,6 +76,7 @@ struct App {
     dark_mode: bool,
     text: String,
     size_option: SizeOption,
+    vertical: bool,
 }
 impl Application for App {
     type Executor = iced::executor::Default;
@@ -92,6 +102,7 @@ impl Application for App {
                 dark_mode: false,
                 text: "Text Input".into(),
                 size_option: SizeOption::Static,
+                vertical: false,
             },
             iced::Command::none(),
         )
@@ -154,6 +165,11 @@ impl Application for App {
                 self.size_option = so;
                 self.title = self.size_option.to_string();
             }
+            Message::VerticalChange(v) => {
+                self.vertical = v;
+                self.title = v.to_string();
+            }
+            Message::OpenMenu => return menu_bar::open(menu_bar::Id::new("menu_bar"), 0),
         }
         iced::Command::none()
     }
@@ -166,38 +182,80 @@ impl Application for App {
         );
 
         let mb = match self.size_option {
-            SizeOption::Uniform => {
-                menu_bar!(menu_1(self), menu_2(self), menu_3(self), menu_4(self))
-                    .item_width(ItemWidth::Uniform(180))
-                    .item_height(ItemHeight::Uniform(25))
-            }
+            SizeOption::Uniform => menu_bar!(
+                menu_1(self),
+                menu_2(self),
+                menu_3(self),
+                menu_4(self),
+                menu_6(self)
+            )
+            .item_width(ItemWidth::Uniform(180))
+            .item_height(ItemHeight::Uniform(25)),
             SizeOption::Static => menu_bar!(
                 menu_1(self),
                 menu_2(self),
                 menu_3(self),
                 menu_4(self),
                 menu_5(self),
+                menu_6(self),
             )
             .item_width(ItemWidth::Static(180))
             .item_height(ItemHeight::Static(25)),
+            SizeOption::Flex => menu_bar!(
+                menu_1(self),
+                menu_2(self),
+                menu_3(self),
+                menu_4(self),
+                menu_5(self),
+                menu_6(self),
+            )
+            .item_width(ItemWidth::Static(180))
+            .item_height(ItemHeight::Flex(25)),
         }
         .spacing(4.0)
         .bounds_expand(30)
+        .open_delay(Duration::from_millis(100))
+        .close_delay(Duration::from_millis(300))
+        .orientation(if self.vertical {
+            Orientation::Vertical
+        } else {
+            Orientation::Horizontal
+        })
         .path_highlight(Some(PathHighlight::MenuActive))
+        .id(menu_bar::Id::new("menu_bar"))
         .close_condition(CloseCondition {
             leave: true,
             click_outside: false,
             click_inside: false,
         });
 
-        let r = if self.flip_h {
-            row!(pick_size_option, horizontal_space(Length::Fill), mb,)
-        } else {
-            row!(mb, horizontal_space(Length::Fill), pick_size_option)
-        }
-        .padding([2, 8])
+        let options = row!(
+            button("Open menu").on_press(Message::OpenMenu),
+            checkbox("Vertical", self.vertical, Message::VerticalChange),
+            pick_size_option
+        )
+        .spacing(8)
         .align_items(alignment::Alignment::Center);
 
+        // a vertical menu bar is shown as a sidebar below the top bar
+        let (r, sidebar) = if self.vertical {
+            (
+                row!(horizontal_space(Length::Fill), options),
+                container(mb).padding(8),
+            )
+        } else if self.flip_h {
+            (
+                row!(options, horizontal_space(Length::Fill), mb),
+                container(col![]),
+            )
+        } else {
+            (
+                row!(mb, horizontal_space(Length::Fill), options),
+                container(col![]),
+            )
+        };
+        let r = r.padding([2, 8]).align_items(alignment::Alignment::Center);
+
         let top_bar_style: fn(&iced::Theme) -> container::Appearance =
             |_theme| container::Appearance {
                 background: Some(Color::TRANSPARENT.into()),
@@ -209,7 +267,7 @@ impl Application for App {
             background: Some(theme.extended_palette().primary.base.color.into()),
             ..Default::default()
         };
-        let back = container(col![])
+        let back = container(sidebar)
             .width(Length::Fill)
             .height(Length::Fill)
             .style(back_style);
@@ -531,10 +589,15 @@ fn menu_3<'a>(app: &App) -> MenuTree<'a, Message, iced::Renderer> {
         vec![
             menu_tree!(labeled_button("Flip Horizontal", Message::FlipHorizontal)
                 .width(Length::Fill)
-                .height(Length::Fill)),
+                .height(Length::Fill))
+            .shortcut(Shortcut::new(Modifiers::CTRL, KeyCode::H)),
             menu_tree!(labeled_button("Flip Vertical", Message::FlipVertical)
                 .width(Length::Fill)
-                .height(Length::Fill)),
+                .height(Length::Fill))
+            .shortcut(Shortcut::new(
+                Modifiers::CTRL | Modifiers::SHIFT,
+                KeyCode::V,
+            )),
             separator(),
             menu_tree!(row![toggler(
                 Some("Dark Mode".into()),
@@ -725,3 +788,37 @@ fn menu_5<'a>(app: &App) -> MenuTree<'a, Message, iced::Renderer> {
 
     root
 }
+
+fn menu_6<'a>(app: &App) -> MenuTree<'a, Message, iced::Renderer> {
+    let size_options: Vec<MenuTree<'a, Message, iced::Renderer>> = SizeOption::ALL
+        .iter()
+        .map(|&option| {
+            MenuItem::radio(
+                option.to_string(),
+                option,
+                Some(app.size_option),
+                Message::SizeOption,
+            )
+            .into()
+        })
+        .collect();
+
+    menu_tree(
+        debug_button("Items"),
+        vec![
+            MenuItem::action("Save", Message::Debug("Save".into()))
+                .shortcut(Shortcut::new(Modifiers::CTRL, KeyCode::S))
+                .into(),
+            MenuItem::action("Close", Message::Debug("Close".into()))
+                .icon(iced_aw::graphics::icons::Icon::X)
+                .into(),
+            MenuItem::action("Print", Message::Debug("Print".into()))
+                .enabled(false)
+                .into(),
+            MenuItem::separator().into(),
+            MenuItem::checkbox("Checkbox", app.check, Message::CheckChange).into(),
+            MenuItem::separator().into(),
+            MenuTree::with_children(MenuItem::submenu("Size"), size_options),
+        ],
+    )
+}
@@ -0,0 +1,59 @@
+use iced::{
+    widget::{Column, Container, Text},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+use iced_aw::RangeSlider;
+
+fn main() -> iced::Result {
+    RangeSliderExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    RangeChanged((u32, u32)),
+}
+
+struct RangeSliderExample {
+    range: (u32, u32),
+}
+
+impl Sandbox for RangeSliderExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        RangeSliderExample { range: (20, 80) }
+    }
+
+    fn title(&self) -> String {
+        String::from("RangeSlider example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::RangeChanged(range) => self.range = range,
+        }
+    }
+
+    fn view(&self) -> Element<Message> {
+        let slider = RangeSlider::new(0..=100, self.range, Message::RangeChanged)
+            .step(5)
+            .tick_marks(10)
+            .show_labels(true)
+            .width(Length::Fixed(300.0));
+
+        let status = Text::new(format!("Selected {} - {}", self.range.0, self.range.1));
+
+        Container::new(
+            Column::new()
+                .spacing(20)
+                .align_items(Alignment::Center)
+                .push(slider)
+                .push(status),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center_x()
+        .center_y()
+        .into()
+    }
+}
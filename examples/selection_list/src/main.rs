@@ -1,5 +1,5 @@
 use iced::{
-    widget::{button, Column, Container, Space, Text},
+    widget::{button, text, Column, Container, Row, Space, Text},
     Alignment, Element, Font, Length, Sandbox, Settings,
 };
 use iced_aw::{selection_list::SelectionList, SelectionListStyles};
@@ -14,11 +14,14 @@ struct Example {
     selected_language: String,
     selected_index: usize,
     manual_select: Option<usize>,
+    selected_languages: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
 enum Message {
     LanguageSelected((usize, String)),
+    LanguagesSelected(Vec<(usize, String)>),
+    Reorder(usize, usize),
     AddAtSelection,
     ManualSelection,
 }
@@ -54,6 +57,16 @@ impl Sandbox for Example {
                     self.vec.push("Rusty".into());
                 }
             }
+            Message::LanguagesSelected(languages) => {
+                self.selected_languages = languages
+                    .into_iter()
+                    .map(|(_, language)| language)
+                    .collect();
+            }
+            Message::Reorder(from, to) => {
+                let language = self.vec.remove(from);
+                self.vec.insert(to, language);
+            }
             Message::AddAtSelection => {
                 self.vec
                     .insert(self.selected_index, "Java OH NOES!".to_owned());
@@ -83,6 +96,35 @@ impl Sandbox for Example {
         .width(Length::Shrink)
         .height(Length::Fixed(100.0));
 
+        let multi_selection_list = SelectionList::new(&self.vec[..], Message::LanguageSelected)
+            .multi_select(Message::LanguagesSelected)
+            .style(SelectionListStyles::Checkbox)
+            .width(Length::Fixed(200.0))
+            .height(Length::Fixed(100.0));
+
+        let custom_selection_list = SelectionList::with_view(
+            &self.vec[..],
+            Message::LanguageSelected,
+            |language, state| {
+                Row::new()
+                    .spacing(10)
+                    .push(text(language).size(14))
+                    .push(
+                        text(if state.is_selected {
+                            "selected"
+                        } else {
+                            "language"
+                        })
+                        .size(10),
+                    )
+                    .into()
+            },
+        )
+        .on_reorder(Message::Reorder)
+        .item_height(30.0)
+        .width(Length::Fixed(200.0))
+        .height(Length::Fixed(100.0));
+
         let mut content = Column::new()
             .width(Length::Fill)
             .align_items(Alignment::Center)
@@ -91,7 +133,11 @@ impl Sandbox for Example {
             .push(Text::new("Which is your favorite language?"))
             .push(Text::new(format!("{:?}", self.selected_language)))
             .push(button("press to add at selection").on_press(Message::AddAtSelection))
-            .push(button("Manual select Index 2").on_press(Message::ManualSelection));
+            .push(button("Manual select Index 2").on_press(Message::ManualSelection))
+            .push(multi_selection_list)
+            .push(Text::new("Which languages do you know?"))
+            .push(Text::new(self.selected_languages.join(", ")))
+            .push(custom_selection_list);
 
         content = content.push(Space::with_height(Length::Fixed(800.0)));
 
@@ -3,7 +3,7 @@ use iced::{
     widget::{container, text, Button, Column, Row, Text, TextInput},
     Alignment, Application, Command, Element, Length, Settings, Theme,
 };
-use iced_aw::{TabBar, TabLabel};
+use iced_aw::{tab_bar::Overflow, TabBar, TabLabel};
 
 fn main() -> iced::Result {
     TabBarExample::run(Settings::default())
@@ -13,6 +13,7 @@ fn main() -> iced::Result {
 enum Message {
     TabSelected(usize),
     TabClosed(usize),
+    TabReordered(usize, usize),
     TabLabelInputChanged(String),
     TabContentInputChanged(String),
     NewTab,
@@ -91,6 +92,20 @@ impl Application for TabBarExample {
                     };
                     println!("active tab after: {}", state.active_tab);
                 }
+                Message::TabReordered(from, to) => {
+                    let tab = state.tabs.remove(from);
+                    state.tabs.insert(to, tab);
+                    // keep the same tab active
+                    state.active_tab = if state.active_tab == from {
+                        to
+                    } else if from < state.active_tab && state.active_tab <= to {
+                        state.active_tab - 1
+                    } else if to <= state.active_tab && state.active_tab < from {
+                        state.active_tab + 1
+                    } else {
+                        state.active_tab
+                    };
+                }
                 Message::TabLabelInputChanged(value) => state.new_tab_label = value,
                 Message::TabContentInputChanged(value) => state.new_tab_content = value,
                 Message::NewTab => {
@@ -151,14 +166,30 @@ impl Application for TabBarExample {
                             .iter()
                             .fold(
                                 TabBar::new(Message::TabSelected),
-                                |tab_bar, (tab_label, _)| {
+                                |tab_bar, (tab_label, content)| {
                                     // manually create a new index for the new tab
                                     // starting from 0, when there is no tab created yet
                                     let idx = tab_bar.size();
-                                    tab_bar.push(idx, TabLabel::Text(tab_label.to_owned()))
+                                    let label = if content.is_empty() {
+                                        // mark the tabs without content with a dot
+                                        TabLabel::Element(
+                                            Row::new()
+                                                .push(Text::new(tab_label.to_owned()).size(32))
+                                                .push(Text::new("•").size(16))
+                                                .align_items(Alignment::Start)
+                                                .spacing(4)
+                                                .into(),
+                                        )
+                                    } else {
+                                        TabLabel::Text(tab_label.to_owned())
+                                    };
+                                    tab_bar.push(idx, label)
                                 },
                             )
                             .on_close(Message::TabClosed)
+                            .on_reorder(Message::TabReordered)
+                            .set_active_tab(&state.active_tab)
+                            .overflow(Overflow::Scroll)
                             .tab_width(Length::Shrink)
                             .spacing(5.0)
                             .padding(5.0)
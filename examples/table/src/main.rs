@@ -0,0 +1,147 @@
+use iced::{
+    font,
+    widget::{text, Button, Column, Container, Scrollable, Text},
+    Application, Command, Element, Length, Settings, Theme,
+};
+use iced_aw::{SortOrder, Table, TableColumn};
+
+fn main() -> iced::Result {
+    TableExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Sort(usize, SortOrder),
+    Resize(usize, f32),
+    Select(usize),
+    Remove(usize),
+    FontLoaded(Result<(), font::Error>),
+}
+
+struct Person {
+    name: String,
+    age: u32,
+    city: String,
+}
+
+struct TableExample {
+    people: Vec<Person>,
+    widths: [f32; 4],
+    sort: Option<(usize, SortOrder)>,
+    selected: Option<usize>,
+}
+
+impl Application for TableExample {
+    type Message = Message;
+    type Theme = Theme;
+    type Executor = iced::executor::Default;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let people = [
+            ("Alice", 32, "Berlin"),
+            ("Bob", 27, "Paris"),
+            ("Carol", 45, "Madrid"),
+            ("Dave", 19, "Rome"),
+            ("Eve", 38, "Vienna"),
+        ]
+        .into_iter()
+        .map(|(name, age, city)| Person {
+            name: name.to_owned(),
+            age,
+            city: city.to_owned(),
+        })
+        .collect();
+
+        (
+            TableExample {
+                people,
+                widths: [150.0, 80.0, 150.0, 100.0],
+                sort: None,
+                selected: None,
+            },
+            font::load(iced_aw::graphics::icons::ICON_FONT_BYTES).map(Message::FontLoaded),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Table example")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Sort(column, order) => {
+                self.people.sort_by(|a, b| {
+                    let ordering = match column {
+                        0 => a.name.cmp(&b.name),
+                        1 => a.age.cmp(&b.age),
+                        _ => a.city.cmp(&b.city),
+                    };
+                    match order {
+                        SortOrder::Ascending => ordering,
+                        SortOrder::Descending => ordering.reverse(),
+                    }
+                });
+                self.sort = Some((column, order));
+                self.selected = None;
+            }
+            Message::Resize(column, width) => self.widths[column] = width,
+            Message::Select(row) => self.selected = Some(row),
+            Message::Remove(row) => {
+                let _ = self.people.remove(row);
+                self.selected = None;
+            }
+            Message::FontLoaded(_) => {}
+        }
+
+        Command::none()
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let columns = vec![
+            TableColumn::new("Name", self.widths[0]).sortable(true),
+            TableColumn::new("Age", self.widths[1]).sortable(true),
+            TableColumn::new("City", self.widths[2]).sortable(true),
+            TableColumn::new("", self.widths[3]).resizable(false),
+        ];
+
+        let mut table = Table::new(columns)
+            .on_sort(Message::Sort)
+            .on_resize(Message::Resize)
+            .on_select(Message::Select)
+            .selected(self.selected);
+
+        if let Some((column, order)) = self.sort {
+            table = table.sort(column, order);
+        }
+
+        for (index, person) in self.people.iter().enumerate() {
+            table = table.push_row(vec![
+                Text::new(&person.name).into(),
+                Text::new(person.age.to_string()).into(),
+                Text::new(&person.city).into(),
+                Button::new(text("Remove").size(12))
+                    .on_press(Message::Remove(index))
+                    .into(),
+            ]);
+        }
+
+        let selected = Text::new(
+            self.selected
+                .and_then(|row| self.people.get(row))
+                .map_or_else(
+                    || String::from("Nothing selected"),
+                    |person| format!("Selected {}", person.name),
+                ),
+        );
+
+        Container::new(Scrollable::new(
+            Column::new().spacing(20).push(table).push(selected),
+        ))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .center_x()
+        .into()
+    }
+}
@@ -176,6 +176,12 @@ impl Application for TabBarExample {
                     .tab_bar_position(match position {
                         TabBarPosition::Top => iced_aw::TabBarPosition::Top,
                         TabBarPosition::Bottom => iced_aw::TabBarPosition::Bottom,
+                        TabBarPosition::Left => iced_aw::TabBarPosition::Left,
+                        TabBarPosition::Right => iced_aw::TabBarPosition::Right,
+                    })
+                    .tab_label_alignment(match position {
+                        TabBarPosition::Top | TabBarPosition::Bottom => Horizontal::Center,
+                        TabBarPosition::Left | TabBarPosition::Right => Horizontal::Left,
                     })
                     .into()
             }
@@ -10,10 +10,17 @@ pub enum TabBarPosition {
     #[default]
     Top,
     Bottom,
+    Left,
+    Right,
 }
 
 impl TabBarPosition {
-    pub const ALL: [TabBarPosition; 2] = [TabBarPosition::Top, TabBarPosition::Bottom];
+    pub const ALL: [TabBarPosition; 4] = [
+        TabBarPosition::Top,
+        TabBarPosition::Bottom,
+        TabBarPosition::Left,
+        TabBarPosition::Right,
+    ];
 }
 
 impl From<TabBarPosition> for String {
@@ -21,6 +28,8 @@ impl From<TabBarPosition> for String {
         String::from(match position {
             TabBarPosition::Top => "Top",
             TabBarPosition::Bottom => "Bottom",
+            TabBarPosition::Left => "Left",
+            TabBarPosition::Right => "Right",
         })
     }
 }
@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+
+use iced::{
+    widget::{scrollable, text, Column, Container, Row},
+    Alignment, Element, Length, Sandbox, Settings,
+};
+use iced_aw::{TreeNode, TreeView};
+
+fn main() -> iced::Result {
+    TreeViewExample::run(Settings::default())
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Expand(usize),
+    Collapse(usize),
+    Select(usize),
+}
+
+/// A file or a directory whose children are only loaded when it is expanded.
+struct Entry {
+    name: String,
+    is_dir: bool,
+    children: Option<Vec<usize>>,
+}
+
+struct TreeViewExample {
+    entries: Vec<Entry>,
+    roots: Vec<usize>,
+    expanded: HashSet<usize>,
+    selected: Option<usize>,
+}
+
+impl TreeViewExample {
+    /// Adds an entry and returns its index.
+    fn add(&mut self, name: String, is_dir: bool) -> usize {
+        self.entries.push(Entry {
+            name,
+            is_dir,
+            children: None,
+        });
+        self.entries.len() - 1
+    }
+
+    /// Pretends to read the contents of a directory.
+    fn load(&mut self, id: usize) {
+        if self.entries[id].children.is_some() {
+            return;
+        }
+
+        let name = self.entries[id].name.clone();
+        let mut children = Vec::new();
+        for i in 0..3 {
+            children.push(self.add(format!("{name}_dir{i}"), true));
+        }
+        for i in 0..2 {
+            children.push(self.add(format!("{name}_file{i}.txt"), false));
+        }
+
+        self.entries[id].children = Some(children);
+    }
+
+    fn node(&self, id: usize) -> TreeNode<'_, usize, Message> {
+        let entry = &self.entries[id];
+        let content = Row::new()
+            .spacing(5)
+            .push(text(if entry.is_dir { "[D]" } else { "[F]" }).size(12))
+            .push(text(&entry.name).size(14));
+
+        let children = entry
+            .children
+            .iter()
+            .flatten()
+            .map(|child| self.node(*child))
+            .collect();
+
+        TreeNode::with_children(id, content, children)
+            .has_children(entry.is_dir)
+            .expanded(self.expanded.contains(&id))
+    }
+}
+
+impl Sandbox for TreeViewExample {
+    type Message = Message;
+
+    fn new() -> Self {
+        let mut example = Self {
+            entries: Vec::new(),
+            roots: Vec::new(),
+            expanded: HashSet::new(),
+            selected: None,
+        };
+
+        example.roots = vec![
+            example.add("src".into(), true),
+            example.add("examples".into(), true),
+            example.add("Cargo.toml".into(), false),
+            example.add("README.md".into(), false),
+        ];
+
+        example
+    }
+
+    fn title(&self) -> String {
+        String::from("TreeView example")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Expand(id) => {
+                self.load(id);
+                self.expanded.insert(id);
+            }
+            Message::Collapse(id) => {
+                self.expanded.remove(&id);
+            }
+            Message::Select(id) => self.selected = Some(id),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let tree_view = TreeView::new(self.roots.iter().map(|root| self.node(*root)).collect())
+            .selected(self.selected)
+            .on_expand(Message::Expand)
+            .on_collapse(Message::Collapse)
+            .on_select(Message::Select);
+
+        let content = Column::new()
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .push(text(format!(
+                "Selected: {}",
+                self.selected
+                    .map_or("none", |id| self.entries[id].name.as_str())
+            )))
+            .push(
+                Container::new(scrollable(tree_view))
+                    .width(Length::Fixed(300.0))
+                    .height(Length::Fixed(400.0)),
+            );
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .padding(40)
+            .into()
+    }
+}
@@ -10,6 +10,9 @@ pub mod clock;
 #[cfg(feature = "color_picker")]
 pub mod color;
 
+#[cfg(any(feature = "menu", feature = "context_menu"))]
+pub(crate) mod operation;
+
 pub mod overlay;
 
 pub mod renderer;
@@ -0,0 +1,98 @@
+//! Operations changing or querying the state of the widgets of this crate.
+use iced_widget::core::{
+    widget::{operation, Id, Operation},
+    Rectangle,
+};
+use std::{any::Any, cell::Cell, marker::PhantomData};
+
+/// Defines the identifier of a widget that can be targeted by [`Command`]s.
+///
+/// [`Command`]: iced_widget::runtime::Command
+macro_rules! widget_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name(iced_widget::core::widget::Id);
+
+        impl $name {
+            /// Creates a custom identifier.
+            pub fn new(id: impl Into<std::borrow::Cow<'static, str>>) -> Self {
+                Self(iced_widget::core::widget::Id::new(id))
+            }
+
+            /// Creates a unique identifier.
+            ///
+            /// This function produces a different identifier every time it is called.
+            #[must_use]
+            pub fn unique() -> Self {
+                Self(iced_widget::core::widget::Id::unique())
+            }
+        }
+
+        impl From<$name> for iced_widget::core::widget::Id {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+    };
+}
+pub(crate) use widget_id;
+
+/// An [`Operation`] changing or querying the state `S`
+/// of the widget with the target [`Id`].
+pub struct StateOperation<S, F, T> {
+    /// The identifier of the widget.
+    target: Id,
+    /// Changes or queries the state.
+    operate: F,
+    /// The result of the operation.
+    output: Cell<Option<T>>,
+    /// The type of the state.
+    state: PhantomData<S>,
+}
+
+impl<S, F, T> StateOperation<S, F, T>
+where
+    F: FnMut(&mut S) -> Option<T>,
+{
+    /// Creates a new [`StateOperation`] on the widget with the given identifier.
+    pub fn new(target: impl Into<Id>, operate: F) -> Self {
+        Self {
+            target: target.into(),
+            operate,
+            output: Cell::new(None),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<S, F, T> Operation<T> for StateOperation<S, F, T>
+where
+    S: 'static,
+    F: FnMut(&mut S) -> Option<T>,
+{
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        if id != Some(&self.target) {
+            return;
+        }
+
+        if let Some(state) = state.downcast_mut::<S>() {
+            self.output.set((self.operate)(state));
+        }
+    }
+
+    fn finish(&self) -> operation::Outcome<T> {
+        self.output
+            .take()
+            .map_or(operation::Outcome::None, operation::Outcome::Some)
+    }
+}
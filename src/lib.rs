@@ -91,7 +91,7 @@ mod platform {
     #[cfg(feature = "grid")]
     pub use {
         crate::native::grid,
-        grid::{Grid, Strategy},
+        grid::{Grid, GridItem, LazyGrid, Strategy},
     };
 
     #[doc(no_inline)]
@@ -127,6 +127,24 @@ mod platform {
         crate::native::number_input, crate::style::NumberInputStyles, number_input::NumberInput,
     };
 
+    #[doc(no_inline)]
+    #[cfg(feature = "range_slider")]
+    pub use {
+        crate::native::range_slider, crate::style::RangeSliderStyles, range_slider::RangeSlider,
+    };
+
+    #[doc(no_inline)]
+    #[cfg(feature = "combo_box")]
+    pub use {crate::native::combo_box, crate::style::ComboBoxStyles, combo_box::ComboBox};
+
+    #[doc(no_inline)]
+    #[cfg(feature = "tree_view")]
+    pub use {
+        crate::native::tree_view,
+        crate::style::TreeViewStyles,
+        tree_view::{TreeNode, TreeView},
+    };
+
     #[doc(no_inline)]
     #[cfg(feature = "selection_list")]
     pub use {
@@ -138,12 +156,21 @@ mod platform {
     #[cfg(feature = "split")]
     pub use {crate::native::split, crate::style::SplitStyles, split::Split};
 
+    #[doc(no_inline)]
+    #[cfg(feature = "table")]
+    pub use {
+        crate::native::table,
+        crate::style::TableStyles,
+        table::{SortOrder, Table, TableColumn},
+    };
+
     #[doc(no_inline)]
     #[cfg(feature = "menu")]
     pub use {
         crate::native::menu,
         crate::native::menu::{
-            CloseCondition, ItemHeight, ItemWidth, MenuBar, MenuTree, PathHighlight,
+            CloseCondition, ItemHeight, ItemWidth, MenuBar, MenuItem, MenuTree, Orientation,
+            PathHighlight, Shortcut,
         },
     };
 
@@ -0,0 +1,994 @@
+//! Search and select a value from a filtered list of options.
+//!
+//! *This API requires the following crate features to be activated: `combo_box`*
+use std::{
+    borrow::Cow,
+    cell::{Cell, OnceCell},
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Range,
+};
+
+use iced_widget::{
+    container,
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        keyboard::{self, KeyCode},
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer,
+        text::{self, LineHeight},
+        touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+    },
+    runtime::Font,
+    scrollable, text_input, TextInput,
+};
+
+use crate::native::overlay::ComboBoxOverlay;
+use crate::native::selection_list::{self, SelectionList};
+use crate::style::{combo_box::StyleSheet, selection_list::StyleSheet as ListStyleSheet};
+
+/// A text input paired with a list of the options matching its text.
+///
+/// The options are filtered with a [`Matcher`] and shown in a
+/// [`SelectionList`] of the matching options, with the characters matching the
+/// query highlighted. The list is either shown permanently below the text
+/// input or as a dropdown while the text input is focused.
+///
+/// While the text input is focused, Up, Down, Page Up and Page Down move the
+/// highlighted option, Enter selects it and Escape closes the dropdown.
+///
+/// # Example
+///
+/// ```ignore
+/// # use iced_aw::{ComboBox, combo_box::Matcher};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QueryChanged(String),
+///     Selected((usize, String)),
+/// }
+///
+/// let fruits = vec![String::from("Apple"), String::from("Banana")];
+/// let query = String::new();
+///
+/// let combo_box = ComboBox::new(
+///     &fruits[..],
+///     "Search fruits...",
+///     &query,
+///     Message::QueryChanged,
+///     Message::Selected,
+/// )
+/// .matcher(Matcher::Fuzzy);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer = crate::Renderer>
+where
+    T: Clone + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + ListStyleSheet + container::StyleSheet + text_input::StyleSheet,
+{
+    /// The options to choose from.
+    options: Cow<'a, [T]>,
+    /// The current search query.
+    query: String,
+    /// The text input for the search query.
+    text_input: TextInput<'a, Message, Renderer>,
+    /// The function producing the message when an option is selected until
+    /// it is moved into the list.
+    #[allow(clippy::type_complexity)]
+    on_selected: Cell<Option<Box<dyn Fn((usize, T)) -> Message + 'a>>>,
+    /// The list of the options matching the query, built when first used.
+    list: OnceCell<SelectionList<'a, Match<T>, Message, Renderer>>,
+    /// The matcher filtering the options.
+    matcher: Matcher,
+    /// How the list of options is shown.
+    presentation: Presentation,
+    /// The width of the [`ComboBox`](ComboBox).
+    width: Length,
+    /// The maximum height of the list of options.
+    max_height: f32,
+    /// The padding of the options.
+    padding: f32,
+    /// The text size of the options.
+    text_size: f32,
+    /// The font of the options.
+    font: Font,
+    /// The style of the characters matching the query.
+    style: <Renderer::Theme as StyleSheet>::Style,
+    /// The style of the list of options.
+    list_style: <Renderer::Theme as ListStyleSheet>::Style,
+}
+
+/// How the list of a [`ComboBox`](ComboBox) is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Presentation {
+    /// The list is always shown below the text input.
+    Inline,
+    /// The list drops down over other widgets while the text input is focused.
+    #[default]
+    Dropdown,
+}
+
+/// Decides whether an option matches the search query of a [`ComboBox`](ComboBox).
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Matcher {
+    /// The label starts with the query.
+    #[default]
+    Prefix,
+    /// The label contains the query.
+    Substring,
+    /// The label contains the characters of the query in order.
+    Fuzzy,
+    /// A custom function returning the indices of the matching characters of
+    /// the label, or `None` if the label does not match the query.
+    Custom(fn(query: &str, label: &str) -> Option<Vec<usize>>),
+}
+
+impl Matcher {
+    /// Returns the indices of the characters of the `label` matching the
+    /// `query` or `None` if the `label` does not match. Letter case is ignored
+    /// and an empty query matches every label.
+    #[must_use]
+    pub fn matches(&self, query: &str, label: &str) -> Option<Vec<usize>> {
+        let query: Vec<char> = query.chars().collect();
+        let label_chars: Vec<char> = label.chars().collect();
+
+        match self {
+            Self::Prefix => {
+                let matched = label_chars.len() >= query.len()
+                    && query
+                        .iter()
+                        .zip(&label_chars)
+                        .all(|(a, b)| eq_ignore_case(*a, *b));
+
+                matched.then(|| (0..query.len()).collect())
+            }
+            Self::Substring => {
+                if query.is_empty() {
+                    return Some(Vec::new());
+                }
+
+                label_chars
+                    .windows(query.len())
+                    .position(|window| {
+                        window
+                            .iter()
+                            .zip(&query)
+                            .all(|(a, b)| eq_ignore_case(*a, *b))
+                    })
+                    .map(|start| (start..start + query.len()).collect())
+            }
+            Self::Fuzzy => {
+                let mut indices = Vec::with_capacity(query.len());
+                let mut chars = label_chars.iter().enumerate();
+
+                for c in &query {
+                    let (index, _) = chars.find(|(_, l)| eq_ignore_case(**l, *c))?;
+                    indices.push(index);
+                }
+
+                Some(indices)
+            }
+            Self::Custom(matcher) => matcher(&query.iter().collect::<String>(), label),
+        }
+    }
+}
+
+/// Compares two characters ignoring their case.
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// An option matching the query of a [`ComboBox`](ComboBox).
+#[derive(Clone)]
+struct Match<T> {
+    /// The index of the option.
+    index: usize,
+    /// The option.
+    option: T,
+    /// The label of the option.
+    label: String,
+    /// The indices of the characters matching the query.
+    highlights: Vec<usize>,
+}
+
+impl<T> PartialEq for Match<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.label == other.label
+    }
+}
+
+impl<T> Eq for Match<T> {}
+
+impl<T> Hash for Match<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+        self.label.hash(state);
+    }
+}
+
+impl<T> fmt::Display for Match<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// The state of a [`ComboBox`](ComboBox).
+#[derive(Debug, Default)]
+pub struct State {
+    /// Whether the dropdown is open.
+    pub(crate) is_open: bool,
+}
+
+/// The padding the [`SelectionList`] puts around its options.
+const LIST_PADDING: f32 = 1.0;
+
+impl<'a, T, Message, Renderer> ComboBox<'a, T, Message, Renderer>
+where
+    T: 'a + Clone + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet
+        + ListStyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet
+        + text_input::StyleSheet,
+{
+    /// Creates a new [`ComboBox`](ComboBox) with the given `options`, the
+    /// `placeholder` and current `query` of its text input, the message to
+    /// produce when the query changes and the message to produce when an option
+    /// is selected.
+    pub fn new(
+        options: impl Into<Cow<'a, [T]>>,
+        placeholder: &str,
+        query: &str,
+        on_query_change: impl Fn(String) -> Message + 'a,
+        on_selected: impl Fn((usize, T)) -> Message + 'a,
+    ) -> Self {
+        let padding = 5.0;
+        let text_size = 16.0;
+
+        Self {
+            options: options.into(),
+            query: query.to_owned(),
+            text_input: TextInput::new(placeholder, query)
+                .on_input(on_query_change)
+                .padding(padding)
+                .size(text_size),
+            on_selected: Cell::new(Some(Box::new(on_selected))),
+            list: OnceCell::new(),
+            matcher: Matcher::default(),
+            presentation: Presentation::default(),
+            width: Length::Fill,
+            max_height: 200.0,
+            padding,
+            text_size,
+            font: Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+            list_style: <Renderer::Theme as ListStyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the [`Matcher`] filtering the options of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn matcher(mut self, matcher: Matcher) -> Self {
+        self.matcher = matcher;
+        self
+    }
+
+    /// Sets the [`Presentation`] of the list of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn presentation(mut self, presentation: Presentation) -> Self {
+        self.presentation = presentation;
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self.text_input = self.text_input.width(width);
+        self
+    }
+
+    /// Sets the maximum height of the list of the [`ComboBox`](ComboBox).
+    /// An inline list always takes this height.
+    #[must_use]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the padding of the text input and the options of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the text input and the options of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = size;
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the font of the text input and the options of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the style of the characters matching the query in the list of the
+    /// [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the style of the list of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn list_style(mut self, style: <Renderer::Theme as ListStyleSheet>::Style) -> Self {
+        self.list_style = style;
+        self
+    }
+
+    /// Sets the style of the text input of the [`ComboBox`](ComboBox).
+    #[must_use]
+    pub fn text_input_style(
+        mut self,
+        style: impl Into<<Renderer::Theme as text_input::StyleSheet>::Style>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style);
+        self
+    }
+
+    /// The list of the options matching the query, built when first used.
+    fn list(&self) -> &SelectionList<'a, Match<T>, Message, Renderer> {
+        self.list.get_or_init(|| self.build_list())
+    }
+
+    /// The mutable list of the options matching the query, built when first used.
+    fn list_mut(&mut self) -> &mut SelectionList<'a, Match<T>, Message, Renderer> {
+        let _ = self.list();
+
+        self.list.get_mut().expect("List Missing in Combo Box")
+    }
+
+    /// Filters the options with the query and the matcher and builds the
+    /// [`SelectionList`] showing the matching options.
+    fn build_list(&self) -> SelectionList<'a, Match<T>, Message, Renderer> {
+        let on_selected = self
+            .on_selected
+            .take()
+            .expect("On Selected Missing in Combo Box");
+
+        let matches: Vec<_> = self
+            .options
+            .iter()
+            .enumerate()
+            .filter_map(|(index, option)| {
+                let label = option.to_string();
+
+                self.matcher
+                    .matches(&self.query, &label)
+                    .map(|highlights| Match {
+                        index,
+                        option: option.clone(),
+                        label,
+                        highlights,
+                    })
+            })
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let height = match self.presentation {
+            Presentation::Inline => self.max_height,
+            Presentation::Dropdown => {
+                ((self.text_size + self.padding * 2.0) * matches.len() as f32 + LIST_PADDING * 2.0)
+                    .min(self.max_height)
+            }
+        };
+
+        let mut list = SelectionList::<Match<T>, Message, Renderer>::new_with(
+            matches,
+            move |(_, found): (usize, Match<T>)| on_selected((found.index, found.option)),
+            self.text_size,
+            self.padding,
+            self.list_style,
+            None,
+            self.font,
+        )
+        .height(Length::Fixed(height));
+
+        let (text_size, font, style) = (self.text_size, self.font, self.style);
+        list.list_mut().view = Some(Box::new(move |found: &Match<T>, _| {
+            Element::new(Highlighted {
+                label: found.label.clone(),
+                highlights: found.highlights.clone(),
+                text_size,
+                font,
+                style,
+            })
+        }));
+
+        list
+    }
+
+    /// Whether the list has any options to show.
+    fn has_matches(&self) -> bool {
+        !self.list().options().is_empty()
+    }
+
+    /// Handles the navigation keys while the text input is focused by moving
+    /// the highlighted option of the list.
+    #[allow(clippy::too_many_arguments)]
+    fn on_key_pressed(
+        &mut self,
+        tree: &mut Tree,
+        key_code: KeyCode,
+        input_bounds: Rectangle,
+        list_layout: Option<Layout<'_>>,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match key_code {
+            KeyCode::Escape if state.is_open => {
+                state.is_open = false;
+                event::Status::Captured
+            }
+            KeyCode::Up
+            | KeyCode::Down
+            | KeyCode::PageUp
+            | KeyCode::PageDown
+            | KeyCode::Enter
+            | KeyCode::NumpadEnter => {
+                if let Some(list_layout) = list_layout {
+                    return selection_list::navigate::<Match<T>, Message, Renderer>(
+                        self.list_mut(),
+                        &mut tree.children[1],
+                        key_code,
+                        list_layout,
+                        renderer,
+                        clipboard,
+                        shell,
+                    );
+                }
+
+                if !state.is_open {
+                    let opens = matches!(key_code, KeyCode::Up | KeyCode::Down);
+                    state.is_open = opens && self.has_matches();
+
+                    return if state.is_open {
+                        event::Status::Captured
+                    } else {
+                        event::Status::Ignored
+                    };
+                }
+
+                // The dropdown is laid out by its overlay, but the list only
+                // needs its size to bring the highlighted option into view.
+                let node = self.list().layout(
+                    renderer,
+                    &Limits::new(Size::ZERO, Size::new(input_bounds.width, self.max_height)),
+                );
+                let list = self.list_mut();
+
+                let (status, selected) = with_selection(&mut tree.children[1], |list_tree| {
+                    selection_list::navigate::<Match<T>, Message, Renderer>(
+                        list,
+                        list_tree,
+                        key_code,
+                        Layout::new(&node),
+                        renderer,
+                        clipboard,
+                        shell,
+                    )
+                });
+
+                if selected {
+                    tree.state.downcast_mut::<State>().is_open = false;
+                }
+
+                status
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+}
+
+/// Calls `f` with the tree of the list of a [`ComboBox`](ComboBox) and returns
+/// its status and whether it selected an option.
+pub(crate) fn with_selection(
+    list_tree: &mut Tree,
+    f: impl FnOnce(&mut Tree) -> event::Status,
+) -> (event::Status, bool) {
+    let previous = selection_list::list_state(list_tree)
+        .last_selected_index
+        .take();
+
+    let status = f(list_tree);
+
+    let list_state = selection_list::list_state(list_tree);
+    if list_state.last_selected_index.is_some() {
+        (status, true)
+    } else {
+        list_state.last_selected_index = previous;
+        (status, false)
+    }
+}
+
+/// The label of an option in the list of a [`ComboBox`](ComboBox) with the
+/// characters matching the query highlighted.
+struct Highlighted<Renderer>
+where
+    Renderer: core::text::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The label of the option.
+    label: String,
+    /// The indices of the characters matching the query.
+    highlights: Vec<usize>,
+    /// The text size of the label.
+    text_size: f32,
+    /// The font of the label.
+    font: Font,
+    /// The style of the characters matching the query.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Highlighted<Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let size = renderer.measure(
+            &self.label,
+            self.text_size,
+            LineHeight::default(),
+            self.font,
+            Size::new(f32::INFINITY, f32::INFINITY),
+            text::Shaping::Advanced,
+        );
+
+        Node::new(limits.resolve(size))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let highlight_color = theme.active(self.style).highlight_color;
+
+        let mut x = bounds.x;
+        for (range, highlighted) in runs(&self.label, &self.highlights) {
+            let content = &self.label[range];
+            let width = renderer
+                .measure(
+                    content,
+                    self.text_size,
+                    LineHeight::default(),
+                    self.font,
+                    Size::new(f32::INFINITY, f32::INFINITY),
+                    text::Shaping::Advanced,
+                )
+                .width;
+
+            renderer.fill_text(core::text::Text {
+                content,
+                bounds: Rectangle {
+                    x,
+                    y: bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..bounds
+                },
+                size: self.text_size,
+                line_height: LineHeight::default(),
+                color: if highlighted {
+                    highlight_color
+                } else {
+                    style.text_color
+                },
+                font: self.font,
+                horizontal_alignment: Horizontal::Left,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Advanced,
+            });
+
+            x += width;
+        }
+    }
+}
+
+/// Splits the `label` into byte ranges of consecutive characters that are
+/// either all highlighted or all not highlighted.
+fn runs(label: &str, highlights: &[usize]) -> Vec<(Range<usize>, bool)> {
+    let mut runs: Vec<(Range<usize>, bool)> = Vec::new();
+
+    for (index, (start, c)) in label.char_indices().enumerate() {
+        let highlighted = highlights.contains(&index);
+        let end = start + c.len_utf8();
+
+        match runs.last_mut() {
+            Some((range, last)) if *last == highlighted => range.end = end,
+            _ => runs.push((start..end, highlighted)),
+        }
+    }
+
+    runs
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for ComboBox<'a, T, Message, Renderer>
+where
+    T: 'a + Clone + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet
+        + ListStyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet
+        + text_input::StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![
+            Tree::new(&self.text_input as &dyn Widget<_, _>),
+            Tree::new(self.list() as &dyn Widget<_, _>),
+        ]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[
+            &self.text_input as &dyn Widget<_, _>,
+            self.list() as &dyn Widget<_, _>,
+        ]);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let input = Widget::<Message, Renderer>::layout(&self.text_input, renderer, &limits);
+        let input_size = input.size();
+
+        match self.presentation {
+            Presentation::Inline => {
+                let mut list = self.list().layout(
+                    renderer,
+                    &Limits::new(Size::ZERO, Size::new(input_size.width, self.max_height)),
+                );
+                list.move_to([0.0, input_size.height].into());
+                let height = input_size.height + list.size().height;
+
+                Node::with_children(Size::new(input_size.width, height), vec![input, list])
+            }
+            Presentation::Dropdown => Node::with_children(input_size, vec![input]),
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Native: Layout should have a text input layout");
+        let list_layout = children.next();
+
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+
+        if is_focused {
+            if let Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) = event {
+                let status = self.on_key_pressed(
+                    tree,
+                    key_code,
+                    input_layout.bounds(),
+                    list_layout,
+                    renderer,
+                    clipboard,
+                    shell,
+                );
+
+                if status == event::Status::Captured {
+                    return status;
+                }
+            }
+        }
+
+        if let Some(list_layout) = list_layout {
+            let status = self.list_mut().on_event(
+                &mut tree.children[1],
+                event.clone(),
+                list_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        let status = self.text_input.on_event(
+            &mut tree.children[0],
+            event.clone(),
+            input_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State>()
+            .is_focused();
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            _ if !is_focused => state.is_open = false,
+            Event::Keyboard(
+                keyboard::Event::CharacterReceived(_)
+                | keyboard::Event::KeyPressed {
+                    key_code: KeyCode::Backspace | KeyCode::Delete,
+                    ..
+                },
+            ) => {
+                state.is_open = true;
+
+                // The options matching the query change, so the list starts
+                // over at the top.
+                let list_state = selection_list::list_state(&mut tree.children[1]);
+                list_state.hovered_option = None;
+                list_state.scroll_to = Some(0.0);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(input_layout.bounds()) =>
+            {
+                state.is_open = true;
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Native: Layout should have a text input layout");
+
+        if let Some(list_layout) = children.next() {
+            if cursor.is_over(list_layout.bounds()) {
+                return self.list().mouse_interaction(
+                    &tree.children[1],
+                    list_layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                );
+            }
+        }
+
+        self.text_input.mouse_interaction(
+            &tree.children[0],
+            input_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let input_layout = children
+            .next()
+            .expect("Native: Layout should have a text input layout");
+
+        Widget::<Message, Renderer>::draw(
+            &self.text_input,
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            input_layout,
+            cursor,
+            viewport,
+        );
+
+        if let Some(list_layout) = children.next() {
+            self.list().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                list_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn core::widget::Operation<Message>,
+    ) {
+        let input_layout = layout
+            .children()
+            .next()
+            .expect("Native: Layout should have a text input layout");
+
+        Widget::<Message, Renderer>::operate(
+            &self.text_input,
+            &mut tree.children[0],
+            input_layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let is_open = tree.state.downcast_ref::<State>().is_open;
+
+        if self.presentation != Presentation::Dropdown || !is_open || !self.has_matches() {
+            return None;
+        }
+
+        let input_bounds = layout.children().next()?.bounds();
+        let Tree {
+            state, children, ..
+        } = tree;
+
+        Some(
+            ComboBoxOverlay::new(
+                self.list_mut(),
+                &mut children[1],
+                state.downcast_mut::<State>(),
+                input_bounds,
+            )
+            .overlay(),
+        )
+    }
+}
+
+impl<'a, T, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet
+        + ListStyleSheet
+        + container::StyleSheet
+        + scrollable::StyleSheet
+        + text_input::StyleSheet,
+{
+    fn from(combo_box: ComboBox<'a, T, Message, Renderer>) -> Self {
+        Element::new(combo_box)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{runs, Matcher};
+
+    #[test]
+    fn prefix_matches_start_ignoring_case() {
+        assert_eq!(Matcher::Prefix.matches("ba", "Banana"), Some(vec![0, 1]));
+        assert_eq!(Matcher::Prefix.matches("na", "Banana"), None);
+        assert_eq!(Matcher::Prefix.matches("", "Banana"), Some(vec![]));
+    }
+
+    #[test]
+    fn substring_matches_anywhere() {
+        assert_eq!(
+            Matcher::Substring.matches("NAN", "Banana"),
+            Some(vec![2, 3, 4])
+        );
+        assert_eq!(Matcher::Substring.matches("nab", "Banana"), None);
+    }
+
+    #[test]
+    fn fuzzy_matches_characters_in_order() {
+        assert_eq!(Matcher::Fuzzy.matches("bnn", "Banana"), Some(vec![0, 2, 4]));
+        assert_eq!(Matcher::Fuzzy.matches("nb", "Banana"), None);
+    }
+
+    #[test]
+    fn runs_split_highlighted_characters() {
+        assert_eq!(
+            runs("Banana", &[0, 2, 3]),
+            vec![(0..1, true), (1..2, false), (2..4, true), (4..6, false)]
+        );
+    }
+}
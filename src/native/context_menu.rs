@@ -2,18 +2,31 @@
 //!
 use iced_widget::core::{
     self, event,
+    keyboard::{self, KeyCode},
     layout::{Limits, Node},
     mouse::{self, Button, Cursor},
     overlay, renderer,
-    widget::{tree, Operation, Tree},
+    widget::{self, operation::Focusable, tree, Operation, Tree},
     Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Widget,
 };
+use iced_widget::runtime::Command;
 
+use crate::core::operation::{widget_id, StateOperation};
+#[cfg(feature = "menu")]
+use crate::native::menu::{
+    context::{self, ContextMenus},
+    ItemHeight, ItemWidth, MenuTree,
+};
 use crate::native::overlay::ContextMenuOverlay;
 pub use crate::style::context_menu::StyleSheet;
 
 /// A context menu
 ///
+/// The Menu key and Shift+F10 open the menu below the focused widget of the
+/// underlay, or at the cursor if no widget is focused. Widgets do not report
+/// their own bounds when they are searched for focus, so the menu opens below
+/// the innermost container holding the focused widget, e.g. the whole
+/// [`Column`](iced_widget::Column) of a focused text input.
 ///
 /// # Example
 /// ```ignore
@@ -27,11 +40,34 @@ pub use crate::style::context_menu::StyleSheet;
 ///
 /// let underlay = Text::new("right click me");
 ///
+/// // the menu also opens with the Menu key or Shift+F10
 /// let cm = ContextMenu::new(
 ///     underlay,
 ///     || Button::new("action1").on_press(Message::Action1).into()
 /// );
 /// ```
+///
+/// With the feature `menu` enabled a [`ContextMenu`] can also show [`MenuTree`]s
+/// with nested submenus that behave like the menus of a [`MenuBar`]:
+/// ```ignore
+/// # use iced::widget::{Text, Button};
+/// # use iced_aw::{ContextMenu, MenuTree};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Action1,
+/// }
+///
+/// let underlay = Text::new("right click me");
+///
+/// let cm = ContextMenu::with_menu(
+///     underlay,
+///     vec![MenuTree::new(Button::new("action1").on_press(Message::Action1))]
+/// );
+/// ```
+///
+/// [`MenuTree`]: crate::native::menu::MenuTree
+/// [`MenuBar`]: crate::native::menu::MenuBar
 #[allow(missing_debug_implementations)]
 pub struct ContextMenu<'a, Overlay, Message, Renderer = crate::Renderer>
 where
@@ -46,6 +82,17 @@ where
     overlay: Overlay,
     /// The style of the [`ContextMenu`](ContextMenu).
     style: <Renderer::Theme as StyleSheet>::Style,
+    /// The [`Id`] of the [`ContextMenu`](ContextMenu).
+    id: Option<Id>,
+    /// The menus shown instead of the overlay content.
+    #[cfg(feature = "menu")]
+    menus: Option<Box<dyn ContextMenus<'a, Message, Renderer> + 'a>>,
+    /// The width of the items of the menus.
+    #[cfg(feature = "menu")]
+    item_width: ItemWidth,
+    /// The height of the items of the menus.
+    #[cfg(feature = "menu")]
+    item_height: ItemHeight,
 }
 
 impl<'a, Overlay, Message, Renderer> ContextMenu<'a, Overlay, Message, Renderer>
@@ -68,6 +115,13 @@ where
             underlay: underlay.into(),
             overlay,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
+            id: None,
+            #[cfg(feature = "menu")]
+            menus: None,
+            #[cfg(feature = "menu")]
+            item_width: ItemWidth::Uniform(150),
+            #[cfg(feature = "menu")]
+            item_height: ItemHeight::Uniform(30),
         }
     }
 
@@ -77,6 +131,75 @@ where
         self.style = style;
         self
     }
+
+    /// Sets the [`Id`] of the [`ContextMenu`](ContextMenu) to open, close and query
+    /// it with [`open`], [`close`] and [`is_open`].
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the [`ItemWidth`] of the menus of the [`ContextMenu`](ContextMenu).
+    #[cfg(feature = "menu")]
+    #[must_use]
+    pub fn item_width(mut self, item_width: ItemWidth) -> Self {
+        self.item_width = item_width;
+        self
+    }
+
+    /// Sets the [`ItemHeight`] of the menus of the [`ContextMenu`](ContextMenu).
+    #[cfg(feature = "menu")]
+    #[must_use]
+    pub fn item_height(mut self, item_height: ItemHeight) -> Self {
+        self.item_height = item_height;
+        self
+    }
+}
+
+#[cfg(feature = "menu")]
+impl<'a, Message, Renderer>
+    ContextMenu<'a, fn() -> Element<'a, Message, Renderer>, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + crate::style::menu_bar::StyleSheet,
+{
+    /// Creates a new [`ContextMenu`](ContextMenu) showing menus built from [`MenuTree`]s.
+    ///
+    /// The menus use the layout, the adaptive open direction and the path highlight
+    /// of the menus of a [`MenuBar`](crate::native::menu::MenuBar), so submenus open
+    /// next to their items and the menus flip to stay inside of the window.
+    ///
+    /// `underlay`: The underlying element.
+    ///
+    /// `items`: The items of the menu which will be displayed when `underlay` is clicked.
+    pub fn with_menu<U>(underlay: U, items: Vec<MenuTree<'a, Message, Renderer>>) -> Self
+    where
+        U: Into<Element<'a, Message, Renderer>>,
+    {
+        let mut context_menu = Self::new(underlay, || {
+            Element::new(iced_widget::Space::new(Length::Shrink, Length::Shrink))
+        });
+        context_menu.menus = Some(context::context_menus(items));
+        context_menu
+    }
+
+    /// Sets the style of the menus of a [`ContextMenu`](ContextMenu) created
+    /// with [`with_menu`](ContextMenu::with_menu).
+    ///
+    /// The menus use the default style of a [`MenuBar`](crate::native::menu::MenuBar)
+    /// otherwise.
+    #[must_use]
+    pub fn menu_style(
+        mut self,
+        style: <Renderer::Theme as crate::style::menu_bar::StyleSheet>::Style,
+    ) -> Self {
+        if let Some(menus) = &mut self.menus {
+            menus.set_style(style);
+        }
+        self
+    }
 }
 
 impl<'a, Content, Message, Renderer> Widget<Message, Renderer>
@@ -129,10 +252,26 @@ where
     }
 
     fn children(&self) -> Vec<Tree> {
+        #[cfg(feature = "menu")]
+        if let Some(menus) = &self.menus {
+            return vec![Tree::new(&self.underlay), menus.tree()];
+        }
+
         vec![Tree::new(&self.underlay), Tree::new(&(self.overlay)())]
     }
 
     fn diff(&self, tree: &mut Tree) {
+        #[cfg(feature = "menu")]
+        if let Some(menus) = &self.menus {
+            if tree.children.len() != 2 {
+                tree.children = self.children();
+                return;
+            }
+            tree.children[0].diff(&self.underlay);
+            menus.diff(&mut tree.children[1]);
+            return;
+        }
+
         tree.diff_children(&[&self.underlay, &(self.overlay)()]);
     }
 
@@ -145,7 +284,32 @@ where
     ) {
         let s: &mut State = state.state.downcast_mut();
 
-        if s.show {
+        // the menus were closed by an item or a click outside of them
+        #[cfg(feature = "menu")]
+        if self.menus.is_some() && !context::is_open(&state.children[1]) {
+            s.show = false;
+        }
+
+        let was_shown = s.show;
+        operation.custom(s, self.id.as_ref().map(|id| &id.0));
+
+        // opened by a command below the underlay
+        if s.show && !was_shown {
+            let bounds = layout.bounds();
+            s.cursor_position = Point::new(bounds.x, bounds.y + bounds.height);
+
+            #[cfg(feature = "menu")]
+            if self.menus.is_some() {
+                context::open(&mut state.children[1]);
+            }
+        }
+
+        #[cfg(feature = "menu")]
+        let show_content = s.show && self.menus.is_none();
+        #[cfg(not(feature = "menu"))]
+        let show_content = s.show;
+
+        if show_content {
             let content = (self.overlay)();
             content.as_widget().diff(&mut state.children[1]);
 
@@ -177,10 +341,57 @@ where
                 let s: &mut State = state.state.downcast_mut();
                 s.cursor_position = cursor.position().unwrap_or_default();
                 s.show = !s.show;
+
+                // menus open again at the new position instead
+                #[cfg(feature = "menu")]
+                if self.menus.is_some() {
+                    s.show = true;
+                    context::open(&mut state.children[1]);
+                }
+
                 return event::Status::Captured;
             }
         }
 
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        }) = event
+        {
+            if key_code == KeyCode::Apps
+                || (key_code == KeyCode::F10 && modifiers == keyboard::Modifiers::SHIFT)
+            {
+                // open below the container of the focused widget or at the cursor
+                let mut find_focus = FindFocus {
+                    bounds: layout.bounds(),
+                    focused: None,
+                };
+                self.underlay.as_widget().operate(
+                    &mut state.children[0],
+                    layout,
+                    renderer,
+                    &mut find_focus,
+                );
+                let position = find_focus
+                    .focused
+                    .map(|bounds| Point::new(bounds.x, bounds.y + bounds.height))
+                    .or_else(|| cursor.position_over(layout.bounds()));
+
+                if let Some(position) = position {
+                    let s: &mut State = state.state.downcast_mut();
+                    s.cursor_position = position;
+                    s.show = true;
+
+                    #[cfg(feature = "menu")]
+                    if self.menus.is_some() {
+                        context::open(&mut state.children[1]);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
         self.underlay.as_widget_mut().on_event(
             &mut state.children[0],
             event,
@@ -226,6 +437,27 @@ where
         }
 
         let position = s.cursor_position;
+
+        #[cfg(feature = "menu")]
+        if let Some(menus) = &mut self.menus {
+            // the menus were closed by an item or a click outside of them
+            if !context::is_open(&state.children[1]) {
+                s.show = false;
+                return self.underlay.as_widget_mut().overlay(
+                    &mut state.children[0],
+                    layout,
+                    renderer,
+                );
+            }
+
+            return Some(menus.overlay(
+                &mut state.children[1],
+                position,
+                self.item_width,
+                self.item_height,
+            ));
+        }
+
         let content = (self.overlay)();
         content.as_widget().diff(&mut state.children[1]);
 
@@ -249,6 +481,69 @@ where
     }
 }
 
+widget_id! {
+    /// The identifier of a [`ContextMenu`](ContextMenu).
+    Id
+}
+
+/// Produces a [`Command`] that opens the [`ContextMenu`](ContextMenu) with the given [`Id`]
+/// below its underlay.
+pub fn open<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(StateOperation::new(id, |state: &mut State| {
+        state.show = true;
+        None
+    }))
+}
+
+/// Produces a [`Command`] that closes the [`ContextMenu`](ContextMenu) with the given [`Id`].
+pub fn close<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(StateOperation::new(id, |state: &mut State| {
+        state.show = false;
+        None
+    }))
+}
+
+/// Produces a [`Command`] that reports whether the [`ContextMenu`](ContextMenu)
+/// with the given [`Id`] is open.
+pub fn is_open<Message: 'static>(
+    id: Id,
+    f: impl Fn(bool) -> Message + 'static,
+) -> Command<Message> {
+    Command::widget(StateOperation::new(id, move |state: &mut State| {
+        Some(f(state.show))
+    }))
+}
+
+/// An [`Operation`] looking for a focused widget.
+///
+/// [`Operation::focusable`] does not pass the bounds of the widget, so only the
+/// bounds of the innermost container around it are known.
+struct FindFocus {
+    /// The bounds of the innermost container that is operated on.
+    bounds: Rectangle,
+    /// The bounds of the innermost container around the focused widget.
+    focused: Option<Rectangle>,
+}
+
+impl<T> Operation<T> for FindFocus {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        let outer = std::mem::replace(&mut self.bounds, bounds);
+        operate_on_children(self);
+        self.bounds = outer;
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&widget::Id>) {
+        if state.is_focused() {
+            self.focused = Some(self.bounds);
+        }
+    }
+}
+
 /// The state of the ``context_menu``.
 #[derive(Debug, Default)]
 pub(crate) struct State {
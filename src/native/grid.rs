@@ -2,13 +2,19 @@
 //!
 //! *This API requires the following crate features to be activated: `grid`*
 use iced_widget::core::{
-    self, event,
+    self,
+    alignment::{Horizontal, Vertical},
+    event,
     layout::{Limits, Node},
     mouse::{self, Cursor},
     overlay, renderer,
     widget::{Operation, Tree},
     Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
 };
+use std::collections::HashSet;
+
+pub mod lazy;
+pub use lazy::LazyGrid;
 
 /// A container that distributes its contents in a grid.
 ///
@@ -35,6 +41,129 @@ pub struct Grid<'a, Message, Renderer = crate::Renderer> {
     strategy: Strategy,
     /// The elements in the [`Grid`](Grid).
     elements: Vec<Element<'a, Message, Renderer>>,
+    /// The [`Placement`](Placement) of each element in the [`Grid`](Grid).
+    placements: Vec<Placement>,
+    /// The widths of the columns of the [`Grid`](Grid) when using ``Strategy::Columns``.
+    column_widths: Vec<Length>,
+    /// The horizontal alignment of the elements in each column of the [`Grid`](Grid).
+    column_alignments: Vec<Horizontal>,
+    /// The vertical alignment of the elements in each row of the [`Grid`](Grid).
+    row_alignments: Vec<Vertical>,
+    /// The spacing between the columns of the [`Grid`](Grid).
+    column_spacing: f32,
+    /// The spacing between the rows of the [`Grid`](Grid).
+    row_spacing: f32,
+}
+
+/// An element of a [`Grid`](Grid) that can span multiple rows and columns
+/// and can be placed at an explicit position.
+///
+/// # Example
+///
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::{Grid, GridItem};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let grid = Grid::<Message>::with_columns(3)
+///     .push_item(GridItem::new(Text::new("Header")).column_span(3))
+///     .push_item(GridItem::new(Text::new("Sidebar")).position(1, 0).row_span(2))
+///     .push(Text::new("Content"));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct GridItem<'a, Message, Renderer = crate::Renderer> {
+    /// The content of the [`GridItem`](GridItem).
+    content: Element<'a, Message, Renderer>,
+    /// The [`Placement`](Placement) of the [`GridItem`](GridItem).
+    placement: Placement,
+}
+
+impl<'a, Message, Renderer> GridItem<'a, Message, Renderer> {
+    /// Creates a new [`GridItem`](GridItem) spanning a single cell
+    /// and being placed automatically.
+    pub fn new<E>(content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Self {
+            content: content.into(),
+            placement: Placement::default(),
+        }
+    }
+
+    /// Places the [`GridItem`](GridItem) at the given row and column.
+    /// Items without a position fill the remaining free cells in order.
+    ///
+    /// Items placed on the same cells overlap and later items are drawn on top
+    /// of earlier ones.
+    ///
+    /// Rows after the first [`MAX_ROWS`](MAX_ROWS) rows are clamped to the last
+    /// of them, so an item placed further down overlaps the items in that row.
+    #[must_use]
+    pub fn position(mut self, row: usize, column: usize) -> Self {
+        self.placement.position = Some((row, column));
+        self
+    }
+
+    /// Sets the amount of rows the [`GridItem`](GridItem) spans.
+    ///
+    /// The span is cut at the last of the first [`MAX_ROWS`](MAX_ROWS) rows.
+    #[must_use]
+    pub fn row_span(mut self, span: usize) -> Self {
+        self.placement.row_span = span.max(1);
+        self
+    }
+
+    /// Sets the amount of columns the [`GridItem`](GridItem) spans.
+    #[must_use]
+    pub fn column_span(mut self, span: usize) -> Self {
+        self.placement.column_span = span.max(1);
+        self
+    }
+}
+
+/// The maximum amount of rows explicitly placed or spanned elements can reach.
+///
+/// Rows and row spans of a [`GridItem`](GridItem) beyond it are clamped, so a
+/// far away position doesn't lay out thousands of empty rows. Browsers limit
+/// the implicit rows of a CSS grid the same way.
+pub const MAX_ROWS: usize = 10_000;
+
+/// The requested position and span of an element in a [`Grid`](Grid).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Placement {
+    /// The explicit row and column, if any.
+    position: Option<(usize, usize)>,
+    /// The amount of rows spanned.
+    row_span: usize,
+    /// The amount of columns spanned.
+    column_span: usize,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Self {
+            position: None,
+            row_span: 1,
+            column_span: 1,
+        }
+    }
+}
+
+/// The resolved cell area of an element in a [`Grid`](Grid).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Cell {
+    /// The first row of the area.
+    row: usize,
+    /// The first column of the area.
+    column: usize,
+    /// The amount of rows of the area.
+    row_span: usize,
+    /// The amount of columns of the area.
+    column_span: usize,
 }
 
 /// The [`Strategy`](Strategy) of how to distribute the columns of the [`Grid`](Grid).
@@ -43,6 +172,10 @@ pub enum Strategy {
     /// Use `n` columns.
     Columns(usize),
     /// Try to fit as much columns that have a fixed width.
+    ///
+    /// There are never more columns than elements, so a
+    /// [`Grid`](Grid) with an unbounded width, like inside of a horizontal
+    /// [`Scrollable`](iced_widget::Scrollable), puts all elements in one row.
     ColumnWidth(f32),
 }
 
@@ -68,8 +201,9 @@ where
     #[must_use]
     pub fn with_children(children: Vec<Element<'a, Message, Renderer>>) -> Self {
         Self {
-            strategy: Strategy::default(),
+            placements: vec![Placement::default(); children.len()],
             elements: children,
+            ..Self::default()
         }
     }
 
@@ -79,7 +213,7 @@ where
     pub fn with_columns(columns: usize) -> Self {
         Self {
             strategy: Strategy::Columns(columns),
-            elements: Vec::new(),
+            ..Self::default()
         }
     }
 
@@ -89,7 +223,18 @@ where
     pub fn with_column_width(column_width: f32) -> Self {
         Self {
             strategy: Strategy::ColumnWidth(column_width),
-            elements: Vec::new(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new empty [`Grid`](Grid).
+    /// Elements will be laid out in columns with the given widths.
+    #[must_use]
+    pub fn with_column_widths(widths: Vec<Length>) -> Self {
+        Self {
+            strategy: Strategy::Columns(widths.len()),
+            column_widths: widths,
+            ..Self::default()
         }
     }
 
@@ -101,13 +246,59 @@ where
         self
     }
 
+    /// Sets the widths of the columns of the [`Grid`](Grid) when using ``Strategy::Columns``.
+    /// Columns without a given width will be ``Length::Shrink``.
+    #[must_use]
+    pub fn column_widths(mut self, widths: Vec<Length>) -> Self {
+        self.column_widths = widths;
+        self
+    }
+
+    /// Sets the horizontal alignment of the elements in each column of the [`Grid`](Grid).
+    /// Columns without a given alignment will be aligned to the left.
+    #[must_use]
+    pub fn column_alignments(mut self, alignments: Vec<Horizontal>) -> Self {
+        self.column_alignments = alignments;
+        self
+    }
+
+    /// Sets the vertical alignment of the elements in each row of the [`Grid`](Grid).
+    /// Rows without a given alignment will be aligned to the top.
+    #[must_use]
+    pub fn row_alignments(mut self, alignments: Vec<Vertical>) -> Self {
+        self.row_alignments = alignments;
+        self
+    }
+
+    /// Sets the spacing between the columns of the [`Grid`](Grid).
+    #[must_use]
+    pub fn column_spacing(mut self, spacing: f32) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Sets the spacing between the rows of the [`Grid`](Grid).
+    #[must_use]
+    pub fn row_spacing(mut self, spacing: f32) -> Self {
+        self.row_spacing = spacing;
+        self
+    }
+
+    /// Sets the spacing between the rows and between the columns of the [`Grid`](Grid).
+    #[must_use]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.column_spacing = spacing;
+        self.row_spacing = spacing;
+        self
+    }
+
     /// Adds an [`Element`](Element) to the [`Grid`](Grid).
     #[must_use]
     pub fn push<E>(mut self, element: E) -> Self
     where
         E: Into<Element<'a, Message, Renderer>>,
     {
-        self.elements.push(element.into());
+        self.insert(element);
         self
     }
 
@@ -117,6 +308,140 @@ where
         E: Into<Element<'a, Message, Renderer>>,
     {
         self.elements.push(element.into());
+        self.placements.push(Placement::default());
+    }
+
+    /// Adds a [`GridItem`](GridItem) to the [`Grid`](Grid).
+    #[must_use]
+    pub fn push_item(mut self, item: GridItem<'a, Message, Renderer>) -> Self {
+        self.insert_item(item);
+        self
+    }
+
+    /// Inserts a [`GridItem`](GridItem) into the [`Grid`](Grid).
+    pub fn insert_item(&mut self, item: GridItem<'a, Message, Renderer>) {
+        self.elements.push(item.content);
+        self.placements.push(item.placement);
+    }
+
+    /// Returns the [`Length`](Length) of the given column when using ``Strategy::Columns``.
+    fn column_width(&self, column: usize) -> Length {
+        self.column_widths
+            .get(column)
+            .copied()
+            .unwrap_or(Length::Shrink)
+    }
+
+    /// Calculates the widths of the columns when using ``Strategy::Columns``.
+    fn resolve_column_widths(
+        &self,
+        columns: usize,
+        cells: &[Cell],
+        layouts: &[Node],
+        max_width: f32,
+    ) -> Vec<f32> {
+        let measured = track_sizes(
+            columns,
+            self.column_spacing,
+            cells
+                .iter()
+                .zip(layouts)
+                .filter(|(cell, _)| {
+                    (cell.column..cell.column + cell.column_span)
+                        .any(|column| self.column_width(column) == Length::Shrink)
+                })
+                .map(|(cell, node)| (cell.column, cell.column_span, node.size().width)),
+        );
+
+        let mut widths: Vec<f32> = (0..columns)
+            .map(|column| match self.column_width(column) {
+                Length::Fixed(width) => width,
+                Length::Shrink => measured[column],
+                Length::Fill | Length::FillPortion(_) => 0.0,
+            })
+            .collect();
+
+        let portions: u16 = (0..columns)
+            .map(|column| self.column_width(column).fill_factor())
+            .sum();
+
+        if portions > 0 {
+            let available =
+                max_width - widths.iter().sum::<f32>() - self.column_spacing * (columns - 1) as f32;
+
+            for (column, width) in widths.iter_mut().enumerate() {
+                let factor = self.column_width(column).fill_factor();
+                if factor > 0 {
+                    *width = if available.is_finite() {
+                        (available * f32::from(factor) / f32::from(portions)).max(0.0)
+                    } else {
+                        measured[column]
+                    };
+                }
+            }
+        }
+
+        widths
+    }
+
+    /// Builds the layout of the [`Grid`](Grid).
+    fn build_grid(&self, column_widths: &[f32], cells: &[Cell], layouts: Vec<Node>) -> Node {
+        let rows = cells
+            .iter()
+            .map(|cell| cell.row + cell.row_span)
+            .max()
+            .unwrap_or_default();
+        let row_heights = track_sizes(
+            rows,
+            self.row_spacing,
+            cells
+                .iter()
+                .zip(&layouts)
+                .map(|(cell, node)| (cell.row, cell.row_span, node.size().height)),
+        );
+
+        let column_aligns = track_offsets(column_widths, self.column_spacing);
+        let row_aligns = track_offsets(&row_heights, self.row_spacing);
+        let span_size = |offsets: &[f32], sizes: &[f32], start: usize, span: usize| {
+            offsets[start + span - 1] + sizes[start + span - 1] - offsets[start]
+        };
+
+        let nodes = layouts
+            .into_iter()
+            .zip(cells)
+            .map(|(mut node, cell)| {
+                let cell_width =
+                    span_size(&column_aligns, column_widths, cell.column, cell.column_span);
+                let cell_height = span_size(&row_aligns, &row_heights, cell.row, cell.row_span);
+                let free_width = (cell_width - node.size().width).max(0.0);
+                let free_height = (cell_height - node.size().height).max(0.0);
+
+                let x = match self.column_alignments.get(cell.column) {
+                    Some(Horizontal::Center) => free_width / 2.0,
+                    Some(Horizontal::Right) => free_width,
+                    Some(Horizontal::Left) | None => 0.0,
+                };
+                let y = match self.row_alignments.get(cell.row) {
+                    Some(Vertical::Center) => free_height / 2.0,
+                    Some(Vertical::Bottom) => free_height,
+                    Some(Vertical::Top) | None => 0.0,
+                };
+
+                node.move_to(Point::new(
+                    column_aligns[cell.column] + x,
+                    row_aligns[cell.row] + y,
+                ));
+                node
+            })
+            .collect();
+
+        Node::with_children(
+            Size::new(
+                total_size(column_widths, self.column_spacing),
+                total_size(&row_heights, self.row_spacing),
+            ),
+            nodes,
+        )
     }
 }
 
@@ -128,6 +453,12 @@ where
         Self {
             strategy: Strategy::default(),
             elements: Vec::new(),
+            placements: Vec::new(),
+            column_widths: Vec::new(),
+            column_alignments: Vec::new(),
+            row_alignments: Vec::new(),
+            column_spacing: 0.0,
+            row_spacing: 0.0,
         }
     }
 }
@@ -145,7 +476,14 @@ where
     }
 
     fn width(&self) -> Length {
-        Length::Shrink
+        match self.strategy {
+            Strategy::Columns(columns)
+                if (0..columns).any(|column| self.column_width(column).fill_factor() > 0) =>
+            {
+                Length::Fill
+            }
+            _ => Length::Shrink,
+        }
     }
 
     fn height(&self) -> Length {
@@ -164,47 +502,64 @@ where
                     return Node::new(Size::ZERO);
                 }
 
-                let mut layouts = Vec::with_capacity(self.elements.len());
-                let mut column_widths = Vec::<f32>::with_capacity(columns);
-
-                for (column, element) in (0..columns).cycle().zip(&self.elements) {
-                    let layout = element.as_widget().layout(renderer, limits);
-                    #[allow(clippy::option_if_let_else)]
-                    match column_widths.get_mut(column) {
-                        Some(column_width) => *column_width = column_width.max(layout.size().width),
-                        None => column_widths.insert(column, layout.size().width),
+                let cells = resolve_cells(columns, &self.placements);
+                let mut layouts = self
+                    .elements
+                    .iter()
+                    .map(|element| element.as_widget().layout(renderer, limits))
+                    .collect::<Vec<_>>();
+                let column_widths =
+                    self.resolve_column_widths(columns, &cells, &layouts, limits.max().width);
+
+                // lay out the elements again to fit into their columns
+                if !self.column_widths.is_empty() {
+                    let column_aligns = track_offsets(&column_widths, self.column_spacing);
+
+                    for ((element, cell), layout) in
+                        self.elements.iter().zip(&cells).zip(&mut layouts)
+                    {
+                        let last = cell.column + cell.column_span - 1;
+                        let cell_width =
+                            column_aligns[last] + column_widths[last] - column_aligns[cell.column];
+                        let cell_limits =
+                            Limits::new(Size::ZERO, Size::new(cell_width, limits.max().height));
+                        *layout = element.as_widget().layout(renderer, &cell_limits);
                     }
-
-                    layouts.push(layout);
                 }
 
-                let column_aligns =
-                    std::iter::once(&0.)
-                        .chain(column_widths.iter())
-                        .scan(0., |state, width| {
-                            *state += width;
-                            Some(*state)
-                        });
-                let grid_width = column_widths.iter().sum();
-
-                build_grid(columns, column_aligns, layouts.into_iter(), grid_width)
+                self.build_grid(&column_widths, &cells, layouts)
             }
             // find number of columns by checking how many can fit
             Strategy::ColumnWidth(column_width) => {
-                let column_limits = limits.width(Length::Fixed(column_width));
                 let max_width = limits.max().width;
-                let columns = (max_width / column_width).floor() as usize;
+                // one column per element at most, also if the width is unbounded
+                let columns = if max_width.is_finite() {
+                    ((max_width + self.column_spacing) / (column_width + self.column_spacing))
+                        .floor() as usize
+                } else {
+                    self.elements.len()
+                }
+                .min(self.elements.len());
+
+                if columns == 0 {
+                    return Node::new(Size::ZERO);
+                }
 
+                let cells = resolve_cells(columns, &self.placements);
                 let layouts = self
                     .elements
                     .iter()
-                    .map(|element| element.as_widget().layout(renderer, &column_limits));
-                let column_aligns =
-                    std::iter::successors(Some(0.), |width| Some(width + column_width));
-                #[allow(clippy::cast_precision_loss)] // TODO: possible precision loss
-                let grid_width = (columns as f32) * column_width;
-
-                build_grid(columns, column_aligns, layouts, grid_width)
+                    .zip(&cells)
+                    .map(|(element, cell)| {
+                        let cell_limits = limits.width(Length::Fixed(
+                            column_width * cell.column_span as f32
+                                + self.column_spacing * (cell.column_span - 1) as f32,
+                        ));
+                        element.as_widget().layout(renderer, &cell_limits)
+                    })
+                    .collect::<Vec<_>>();
+
+                self.build_grid(&vec![column_width; columns], &cells, layouts)
             }
         }
     }
@@ -313,31 +668,124 @@ where
     }
 }
 
-/// Builds the layout of the [`Grid`](grid).
-fn build_grid(
-    columns: usize,
-    column_aligns: impl Iterator<Item = f32> + Clone,
-    layouts: impl Iterator<Item = Node> + ExactSizeIterator,
-    grid_width: f32,
-) -> Node {
-    let mut nodes = Vec::with_capacity(layouts.len());
-    let mut grid_height = 0.;
-    let mut row_height = 0.;
-
-    for ((column, column_align), mut node) in (0..columns).zip(column_aligns).cycle().zip(layouts) {
-        if column == 0 {
-            grid_height += row_height;
-            row_height = 0.;
+/// Resolves the [`Placement`](Placement)s of the elements to the [`Cell`](Cell)s they occupy.
+///
+/// Elements with an explicit position are placed first and may overlap each
+/// other. The remaining elements fill the free cells from left to right and top
+/// to bottom in their order.
+///
+/// Only the occupied cells are remembered, and explicit rows and row spans are
+/// clamped to [`MAX_ROWS`](MAX_ROWS), so far away positions don't allocate
+/// empty rows.
+fn resolve_cells(columns: usize, placements: &[Placement]) -> Vec<Cell> {
+    let mut occupied: HashSet<(usize, usize)> = HashSet::new();
+    let occupy = |cell: &Cell, occupied: &mut HashSet<(usize, usize)>| {
+        for row in cell.row..cell.row + cell.row_span {
+            for column in cell.column..cell.column + cell.column_span {
+                let _ = occupied.insert((row, column));
+            }
+        }
+    };
+    let is_free = |row: usize, column: usize, cell: &Cell, occupied: &HashSet<(usize, usize)>| {
+        (row..row + cell.row_span).all(|row| {
+            (column..column + cell.column_span).all(|column| !occupied.contains(&(row, column)))
+        })
+    };
+
+    let mut cells = vec![None; placements.len()];
+
+    for (cell, placement) in cells.iter_mut().zip(placements) {
+        if let Some((row, column)) = placement.position {
+            let row = row.min(MAX_ROWS - 1);
+            let column_span = placement.column_span.min(columns);
+            let resolved = Cell {
+                row,
+                column: column.min(columns - column_span),
+                row_span: placement.row_span.min(MAX_ROWS - row),
+                column_span,
+            };
+            occupy(&resolved, &mut occupied);
+            *cell = Some(resolved);
         }
+    }
 
-        node.move_to(Point::new(column_align, grid_height));
-        row_height = row_height.max(node.size().height);
-        nodes.push(node);
+    let (mut row, mut column) = (0, 0);
+
+    for (cell, placement) in cells.iter_mut().zip(placements) {
+        if cell.is_some() {
+            continue;
+        }
+
+        let mut resolved = Cell {
+            row,
+            column,
+            row_span: placement.row_span.min(MAX_ROWS),
+            column_span: placement.column_span.min(columns),
+        };
+        loop {
+            if column + resolved.column_span > columns {
+                row += 1;
+                column = 0;
+            }
+            if is_free(row, column, &resolved, &occupied) {
+                break;
+            }
+            column += 1;
+        }
+
+        resolved.row = row;
+        resolved.column = column;
+        occupy(&resolved, &mut occupied);
+        *cell = Some(resolved);
+        column += resolved.column_span;
+    }
+
+    cells.into_iter().flatten().collect()
+}
+
+/// Calculates the sizes of the rows or columns of a [`Grid`](Grid).
+///
+/// Each track is as large as the largest element only occupying this track.
+/// Elements spanning multiple tracks distribute their remaining size evenly.
+fn track_sizes(
+    tracks: usize,
+    spacing: f32,
+    items: impl Iterator<Item = (usize, usize, f32)> + Clone,
+) -> Vec<f32> {
+    let mut sizes = vec![0.0_f32; tracks];
+
+    for (start, _span, size) in items.clone().filter(|(_, span, _)| *span == 1) {
+        sizes[start] = sizes[start].max(size);
     }
 
-    grid_height += row_height;
+    for (start, span, size) in items.filter(|(_, span, _)| *span > 1) {
+        let spanned = &mut sizes[start..start + span];
+        let missing = size - total_size(spanned, spacing);
+        if missing > 0.0 {
+            for track in spanned.iter_mut() {
+                *track += missing / span as f32;
+            }
+        }
+    }
+
+    sizes
+}
 
-    Node::with_children(Size::new(grid_width, grid_height), nodes)
+/// Calculates the offsets of the rows or columns of a [`Grid`](Grid).
+fn track_offsets(sizes: &[f32], spacing: f32) -> Vec<f32> {
+    sizes
+        .iter()
+        .scan(0.0, |state, size| {
+            let offset = *state;
+            *state += size + spacing;
+            Some(offset)
+        })
+        .collect()
+}
+
+/// Calculates the total size of the given rows or columns including the spacing between them.
+fn total_size(sizes: &[f32], spacing: f32) -> f32 {
+    sizes.iter().sum::<f32>() + spacing * sizes.len().saturating_sub(1) as f32
 }
 
 impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>> for Element<'a, Message, Renderer>
@@ -349,3 +797,164 @@ where
         Element::new(grid)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        resolve_cells, track_offsets, track_sizes, Cell, Grid, Placement, Strategy, MAX_ROWS,
+    };
+    use iced_widget::{
+        core::{layout::Limits, renderer::Null, Size, Widget},
+        Space,
+    };
+
+    fn cell(row: usize, column: usize, row_span: usize, column_span: usize) -> Cell {
+        Cell {
+            row,
+            column,
+            row_span,
+            column_span,
+        }
+    }
+
+    #[test]
+    fn resolve_cells_auto_flow_test() {
+        let cells = resolve_cells(2, &[Placement::default(); 3]);
+
+        assert_eq!(
+            cells,
+            vec![cell(0, 0, 1, 1), cell(0, 1, 1, 1), cell(1, 0, 1, 1)]
+        );
+    }
+
+    #[test]
+    fn resolve_cells_span_test() {
+        let placements = [
+            Placement {
+                column_span: 3,
+                ..Placement::default()
+            },
+            Placement {
+                row_span: 2,
+                ..Placement::default()
+            },
+            Placement::default(),
+            Placement::default(),
+            Placement::default(),
+        ];
+        let cells = resolve_cells(3, &placements);
+
+        assert_eq!(
+            cells,
+            vec![
+                cell(0, 0, 1, 3),
+                cell(1, 0, 2, 1),
+                cell(1, 1, 1, 1),
+                cell(1, 2, 1, 1),
+                cell(2, 1, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_cells_explicit_position_test() {
+        let placements = [
+            Placement::default(),
+            Placement {
+                position: Some((0, 1)),
+                row_span: 2,
+                ..Placement::default()
+            },
+            Placement::default(),
+            Placement {
+                position: Some((3, 5)),
+                column_span: 4,
+                ..Placement::default()
+            },
+        ];
+        let cells = resolve_cells(2, &placements);
+
+        assert_eq!(
+            cells,
+            vec![
+                cell(0, 0, 1, 1),
+                cell(0, 1, 2, 1),
+                cell(1, 0, 1, 1),
+                cell(3, 0, 1, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_cells_far_away_and_overlapping_position_test() {
+        let placements = [
+            Placement {
+                position: Some((1_000_000_000, 0)),
+                row_span: 5,
+                ..Placement::default()
+            },
+            Placement {
+                position: Some((0, 0)),
+                ..Placement::default()
+            },
+            Placement {
+                position: Some((0, 0)),
+                ..Placement::default()
+            },
+            Placement::default(),
+        ];
+        let cells = resolve_cells(2, &placements);
+
+        assert_eq!(
+            cells,
+            vec![
+                cell(MAX_ROWS - 1, 0, 1, 1),
+                cell(0, 0, 1, 1),
+                cell(0, 0, 1, 1),
+                cell(0, 1, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn track_sizes_test() {
+        let items = [(0, 1, 10.0), (1, 1, 20.0), (0, 2, 50.0), (2, 1, 5.0)];
+        let sizes = track_sizes(3, 0.0, items.into_iter());
+
+        assert_eq!(sizes, vec![20.0, 30.0, 5.0]);
+    }
+
+    #[test]
+    fn track_sizes_spacing_test() {
+        let items = [(0, 1, 10.0), (1, 1, 20.0), (0, 2, 50.0)];
+        let sizes = track_sizes(2, 10.0, items.into_iter());
+
+        assert_eq!(sizes, vec![15.0, 25.0]);
+    }
+
+    #[test]
+    fn track_offsets_test() {
+        assert_eq!(
+            track_offsets(&[10.0, 20.0, 5.0], 5.0),
+            vec![0.0, 15.0, 40.0]
+        );
+    }
+
+    #[test]
+    fn column_width_with_unbounded_width_test() {
+        let grid: Grid<'_, (), Null> = Grid::with_children(vec![
+            Space::new(10.0, 10.0).into(),
+            Space::new(10.0, 10.0).into(),
+        ])
+        .strategy(Strategy::ColumnWidth(100.0));
+
+        // one column per element instead of an unbounded amount of columns
+        let limits = Limits::new(Size::ZERO, Size::new(f32::INFINITY, f32::INFINITY));
+        let node = grid.layout(&Null, &limits);
+        assert_eq!(node.size(), Size::new(200.0, 10.0));
+
+        let limits = Limits::new(Size::ZERO, Size::new(150.0, f32::INFINITY));
+        let node = grid.layout(&Null, &limits);
+        assert_eq!(node.size(), Size::new(100.0, 20.0));
+    }
+}
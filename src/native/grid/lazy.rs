@@ -0,0 +1,433 @@
+//! A grid that only builds, lays out and draws the items inside of its viewport.
+//!
+//! *This API requires the following crate features to be activated: `grid`*
+use std::{cell::RefCell, collections::HashMap, ops::Deref, ops::Range};
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    renderer,
+    widget::{
+        tree::{State, Tag},
+        Tree,
+    },
+    Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A grid of equally sized cells whose items are built on demand.
+///
+/// Only the rows intersecting the viewport of the surrounding
+/// [`Scrollable`](iced_widget::Scrollable) are built, laid out and drawn, so
+/// the cost of a frame does not depend on the number of items.
+///
+/// The state of an item is kept while it is visible and discarded once it
+/// is scrolled out of view. Items can not show overlays. Rows always have the
+/// given height; rows of different or estimated heights are not supported.
+///
+/// # Example
+///
+/// ```ignore
+/// # use iced::widget::{scrollable, text};
+/// # use iced_aw::LazyGrid;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+/// }
+///
+/// let grid = LazyGrid::<Message>::new(100_000, 4, 30.0, |index| {
+///     text(format!("Item {index}")).into()
+/// });
+///
+/// let content = scrollable(grid);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct LazyGrid<'a, Message, Renderer = crate::Renderer> {
+    /// The number of items in the [`LazyGrid`](LazyGrid).
+    count: usize,
+    /// The number of columns of the [`LazyGrid`](LazyGrid).
+    columns: usize,
+    /// The height of every row of the [`LazyGrid`](LazyGrid).
+    row_height: f32,
+    /// The spacing between the columns of the [`LazyGrid`](LazyGrid).
+    column_spacing: f32,
+    /// The spacing between the rows of the [`LazyGrid`](LazyGrid).
+    row_spacing: f32,
+    /// The width of the [`LazyGrid`](LazyGrid).
+    width: Length,
+    /// The function building the item at an index.
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    /// The items built so far, keyed by their index.
+    elements: RefCell<HashMap<usize, Element<'a, Message, Renderer>>>,
+}
+
+impl<'a, Message, Renderer> LazyGrid<'a, Message, Renderer> {
+    /// Creates a new [`LazyGrid`](LazyGrid) of `count` items distributed over
+    /// the given number of `columns`. Every row has the given `row_height`
+    /// and the item at an index is built by `view`.
+    pub fn new<F>(count: usize, columns: usize, row_height: f32, view: F) -> Self
+    where
+        F: Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+    {
+        Self {
+            count,
+            columns: columns.max(1),
+            row_height,
+            column_spacing: 0.0,
+            row_spacing: 0.0,
+            width: Length::Fill,
+            view: Box::new(view),
+            elements: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Sets the spacing between the columns of the [`LazyGrid`](LazyGrid).
+    #[must_use]
+    pub fn column_spacing(mut self, spacing: f32) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Sets the spacing between the rows of the [`LazyGrid`](LazyGrid).
+    #[must_use]
+    pub fn row_spacing(mut self, spacing: f32) -> Self {
+        self.row_spacing = spacing;
+        self
+    }
+
+    /// Sets the spacing between the rows and the columns of the [`LazyGrid`](LazyGrid).
+    #[must_use]
+    pub fn spacing(self, spacing: f32) -> Self {
+        self.column_spacing(spacing).row_spacing(spacing)
+    }
+
+    /// Sets the width of the [`LazyGrid`](LazyGrid).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// The number of rows of the [`LazyGrid`](LazyGrid).
+    fn rows(&self) -> usize {
+        self.count.div_ceil(self.columns)
+    }
+
+    /// The width of a single cell for the given total width.
+    #[allow(clippy::cast_precision_loss)]
+    fn cell_width(&self, width: f32) -> f32 {
+        let spacing = self.column_spacing * (self.columns - 1) as f32;
+
+        ((width - spacing) / self.columns as f32).max(0.0)
+    }
+
+    /// The bounds of the cell of the item at `index`, relative to the grid.
+    #[allow(clippy::cast_precision_loss)]
+    fn cell_bounds(&self, index: usize, width: f32) -> Rectangle {
+        let cell_width = self.cell_width(width);
+        let row = index / self.columns;
+        let column = index % self.columns;
+
+        Rectangle {
+            x: (cell_width + self.column_spacing) * column as f32,
+            y: (self.row_height + self.row_spacing) * row as f32,
+            width: cell_width,
+            height: self.row_height,
+        }
+    }
+
+    /// Calls `f` with the element, tree and layout of every item in the rows
+    /// intersecting the `viewport`, handing the `renderer` back to it.
+    ///
+    /// Every item is built once per [`LazyGrid`](LazyGrid) and the trees of the
+    /// items that are no longer visible are discarded.
+    fn for_each_visible<R>(
+        &self,
+        state: &LazyGridState,
+        layout: Layout<'_>,
+        mut renderer: R,
+        viewport: &Rectangle,
+        mut f: impl FnMut(&mut Element<'a, Message, Renderer>, &mut Tree, Layout<'_>, &mut R),
+    ) where
+        R: Deref<Target = Renderer>,
+        Renderer: core::Renderer,
+    {
+        let bounds = layout.bounds();
+        let visible = visible_range(
+            self.count,
+            self.columns,
+            self.row_height + self.row_spacing,
+            bounds,
+            viewport,
+        );
+        let mut elements = self.elements.borrow_mut();
+        let mut trees = state.trees.borrow_mut();
+
+        trees.retain(|index, _| visible.contains(index));
+
+        for index in visible {
+            let element = elements.entry(index).or_insert_with(|| (self.view)(index));
+            let tree = trees.entry(index).or_insert_with(|| Tree::new(&*element));
+
+            let cell = self.cell_bounds(index, bounds.width);
+            let limits = Limits::new(Size::ZERO, cell.size());
+
+            let mut node = element.as_widget().layout(&renderer, &limits);
+            node.move_to([bounds.x + cell.x, bounds.y + cell.y].into());
+
+            f(
+                element,
+                tree,
+                Layout::with_offset(Vector::ZERO, &node),
+                &mut renderer,
+            );
+        }
+    }
+}
+
+/// The state of a [`LazyGrid`](LazyGrid).
+#[derive(Default)]
+struct LazyGridState {
+    /// The trees of the currently visible items, keyed by their index.
+    ///
+    /// Items scrolled into view while drawing get their tree right away.
+    trees: RefCell<HashMap<usize, Tree>>,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for LazyGrid<'_, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<LazyGridState>()
+    }
+
+    fn state(&self) -> State {
+        State::new(LazyGridState::default())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let trees = tree.state.downcast_mut::<LazyGridState>().trees.get_mut();
+        let mut elements = self.elements.borrow_mut();
+
+        trees.retain(|index, _| *index < self.count);
+        for (index, tree) in trees {
+            tree.diff(
+                &*elements
+                    .entry(*index)
+                    .or_insert_with(|| (self.view)(*index)),
+            );
+        }
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn layout(&self, _renderer: &Renderer, limits: &Limits) -> Node {
+        let rows = self.rows();
+        let height = (self.row_height * rows as f32
+            + self.row_spacing * rows.saturating_sub(1) as f32)
+            .max(0.0);
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        Node::new(limits.resolve(Size::new(limits.max().width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_ref::<LazyGridState>();
+        let mut status = event::Status::Ignored;
+
+        self.for_each_visible(
+            state,
+            layout,
+            renderer,
+            viewport,
+            |element, tree, layout, _| {
+                status = status.merge(element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                ));
+            },
+        );
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<LazyGridState>();
+        let mut interaction = mouse::Interaction::default();
+
+        self.for_each_visible(
+            state,
+            layout,
+            renderer,
+            viewport,
+            |element, tree, layout, _| {
+                if cursor.is_over(layout.bounds()) {
+                    interaction = interaction.max(
+                        element
+                            .as_widget()
+                            .mouse_interaction(tree, layout, cursor, viewport, renderer),
+                    );
+                }
+            },
+        );
+
+        interaction
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<LazyGridState>();
+
+        self.for_each_visible(
+            state,
+            layout,
+            renderer,
+            viewport,
+            |element, tree, layout, renderer| {
+                element
+                    .as_widget()
+                    .draw(tree, renderer, theme, style, layout, cursor, viewport);
+            },
+        );
+    }
+}
+
+/// The range of item indices in the rows intersecting the `viewport`.
+fn visible_range(
+    count: usize,
+    columns: usize,
+    row_stride: f32,
+    bounds: Rectangle,
+    viewport: &Rectangle,
+) -> Range<usize> {
+    if row_stride <= 0.0 {
+        return 0..count;
+    }
+
+    let top = (viewport.y - bounds.y).max(0.0);
+    let bottom = (viewport.y + viewport.height - bounds.y).max(0.0);
+
+    let start = ((top / row_stride) as usize)
+        .saturating_mul(columns)
+        .min(count);
+    let end = ((bottom / row_stride).ceil() as usize)
+        .saturating_mul(columns)
+        .clamp(start, count);
+
+    start..end
+}
+
+impl<'a, Message, Renderer> From<LazyGrid<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Renderer: core::Renderer + 'a,
+    Message: 'a,
+{
+    fn from(grid: LazyGrid<'a, Message, Renderer>) -> Self {
+        Element::new(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{visible_range, LazyGrid};
+    use iced_widget::{
+        core::{
+            layout::Limits,
+            mouse::Cursor,
+            renderer::{Null, Style},
+            widget::Tree,
+            Element, Layout, Rectangle, Size, Widget,
+        },
+        Space,
+    };
+    use std::cell::Cell;
+
+    #[test]
+    fn visible_range_covers_rows_in_viewport() {
+        let bounds = Rectangle::new([0.0, 100.0].into(), [300.0, 10_000.0].into());
+        let viewport = Rectangle::new([0.0, 250.0].into(), [300.0, 100.0].into());
+
+        // Rows 3 and 4 intersect the viewport when each row takes 50 pixels.
+        assert_eq!(visible_range(1000, 4, 50.0, bounds, &viewport), 12..20);
+    }
+
+    #[test]
+    fn visible_range_is_clamped_to_count() {
+        let bounds = Rectangle::new([0.0, 0.0].into(), [300.0, 100.0].into());
+        let viewport = Rectangle::new([0.0, 0.0].into(), [300.0, 1_000.0].into());
+
+        assert_eq!(visible_range(10, 3, 25.0, bounds, &viewport), 0..10);
+        assert_eq!(visible_range(0, 3, 25.0, bounds, &viewport), 0..0);
+    }
+
+    #[test]
+    fn visible_items_are_built_once() {
+        let built = Cell::new(0);
+        let grid: LazyGrid<'_, (), Null> = LazyGrid::new(1000, 4, 50.0, |_| {
+            built.set(built.get() + 1);
+            Element::new(Space::new(10.0, 10.0))
+        });
+        let mut tree = Tree::new(&grid as &dyn Widget<_, _>);
+        grid.diff(&mut tree);
+
+        let node = grid.layout(&Null, &Limits::new(Size::ZERO, Size::new(300.0, 100.0)));
+        let layout = Layout::new(&node);
+        let viewport = Rectangle::new([0.0, 0.0].into(), [300.0, 100.0].into());
+
+        for _ in 0..2 {
+            grid.draw(
+                &tree,
+                &mut Null,
+                &(),
+                &Style::default(),
+                layout,
+                Cursor::Unavailable,
+                &viewport,
+            );
+            let _ = grid.mouse_interaction(&tree, layout, Cursor::Unavailable, &viewport, &Null);
+        }
+
+        // Only the two rows inside of the viewport were built.
+        assert_eq!(built.get(), 8);
+    }
+}
@@ -210,6 +210,36 @@ where
     crate::Grid::with_children(children)
 }
 
+#[cfg(feature = "grid")]
+/// Shortcut helper to create a [`LazyGrid`] Widget.
+///
+/// [`LazyGrid`]: crate::LazyGrid
+#[must_use]
+pub fn lazy_grid<'a, Message, Renderer>(
+    count: usize,
+    columns: usize,
+    row_height: f32,
+    view: impl Fn(usize) -> Element<'a, Message, Renderer> + 'a,
+) -> crate::LazyGrid<'a, Message, Renderer> {
+    crate::LazyGrid::new(count, columns, row_height, view)
+}
+
+#[cfg(feature = "table")]
+/// Shortcut helper to create a [`Table`] Widget.
+///
+/// [`Table`]: crate::Table
+#[must_use]
+pub fn table<Message, Renderer>(
+    columns: Vec<crate::TableColumn>,
+    rows: Vec<Vec<Element<'_, Message, Renderer>>>,
+) -> crate::Table<'_, Message, Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::table::StyleSheet,
+{
+    crate::Table::with_rows(columns, rows)
+}
+
 #[cfg(feature = "wrap")]
 /// Shortcut helper to create a horizontal [`Wrap`] Widget.
 ///
@@ -297,6 +327,73 @@ where
     crate::NumberInput::new(value, max, on_changed)
 }
 
+#[cfg(feature = "range_slider")]
+/// Shortcut helper to create a [`RangeSlider`] Widget.
+///
+/// [`RangeSlider`]: crate::RangeSlider
+#[must_use]
+pub fn range_slider<'a, T, Message, Renderer, F>(
+    range: std::ops::RangeInclusive<T>,
+    values: (T, T),
+    on_change: F,
+) -> crate::RangeSlider<'a, T, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::range_slider::StyleSheet,
+    F: 'a + Fn((T, T)) -> Message,
+    T: num_traits::Num
+        + num_traits::NumAssignOps
+        + num_traits::ToPrimitive
+        + num_traits::FromPrimitive
+        + PartialOrd
+        + std::fmt::Display
+        + Copy,
+{
+    crate::RangeSlider::new(range, values, on_change)
+}
+
+#[cfg(feature = "combo_box")]
+/// Shortcut helper to create a [`ComboBox`] Widget.
+///
+/// [`ComboBox`]: crate::ComboBox
+#[must_use]
+pub fn combo_box<'a, T, Message, Renderer>(
+    options: impl Into<Cow<'a, [T]>>,
+    placeholder: &str,
+    query: &str,
+    on_query_change: impl Fn(String) -> Message + 'a,
+    on_selected: impl Fn((usize, T)) -> Message + 'a,
+) -> crate::ComboBox<'a, T, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: crate::style::combo_box::StyleSheet
+        + crate::style::selection_list::StyleSheet
+        + iced_widget::container::StyleSheet
+        + iced_widget::scrollable::StyleSheet
+        + iced_widget::text_input::StyleSheet,
+    T: 'a + Clone + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    crate::ComboBox::new(options, placeholder, query, on_query_change, on_selected)
+}
+
+#[cfg(feature = "tree_view")]
+/// Shortcut helper to create a [`TreeView`] Widget.
+///
+/// [`TreeView`]: crate::TreeView
+#[must_use]
+pub fn tree_view<Id, Message, Renderer>(
+    nodes: Vec<crate::TreeNode<'_, Id, Message, Renderer>>,
+) -> crate::TreeView<'_, Id, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: crate::style::tree_view::StyleSheet,
+{
+    crate::TreeView::new(nodes)
+}
+
 #[cfg(feature = "selection_list")]
 /// Shortcut helper to create a [`SelectionList`] Widget.
 ///
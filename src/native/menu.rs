@@ -52,14 +52,21 @@
 //! ```
 //!
 
+#[cfg(feature = "context_menu")]
+pub(crate) mod context;
 mod flex;
 pub mod menu_bar;
 mod menu_inner;
+pub mod menu_item;
 pub mod menu_tree;
+mod shortcut;
 
 pub use crate::style::menu_bar::{Appearance, StyleSheet};
 /// A `MenuBar` collects `MenuTree`s and handles
 pub type MenuBar<'a, Message, Renderer> = menu_bar::MenuBar<'a, Message, Renderer>;
-pub use menu_inner::{CloseCondition, ItemHeight, ItemWidth, PathHighlight};
+pub use menu_inner::{CloseCondition, ItemHeight, ItemWidth, Orientation, PathHighlight};
 /// Nested menu is essentially a tree of items, a menu is a collection of items
 pub type MenuTree<'a, Message, Renderer> = menu_tree::MenuTree<'a, Message, Renderer>;
+pub use shortcut::Shortcut;
+/// A standard menu item
+pub type MenuItem<Message, Renderer> = menu_item::MenuItem<Message, Renderer>;
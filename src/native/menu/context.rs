@@ -0,0 +1,263 @@
+//! The menus of a context menu built from menu trees
+
+use super::menu_bar::MenuBarState;
+use super::menu_inner::{CloseCondition, ItemHeight, ItemWidth, Menu, Orientation, PathHighlight};
+use super::menu_tree::MenuTree;
+use crate::style::menu_bar::StyleSheet;
+
+use iced_widget::{
+    core::{
+        overlay, renderer, text,
+        widget::{tree, Tree},
+        Font, Length, Point, Rectangle, Size,
+    },
+    Space,
+};
+use std::time::Duration;
+
+/// The menus shown by a [`ContextMenu`](crate::native::ContextMenu)
+/// instead of its overlay content.
+///
+/// The trait hides the bounds the menus put on the renderer and the theme
+/// from the [`ContextMenu`](crate::native::ContextMenu) widget.
+pub trait ContextMenus<'a, Message, Renderer> {
+    /// Creates the widget tree of the menus.
+    fn tree(&self) -> Tree;
+
+    /// Reconciles the widget tree of the menus.
+    fn diff(&self, tree: &mut Tree);
+
+    /// Sets the style of the menus.
+    fn set_style(&mut self, style: <Renderer::Theme as StyleSheet>::Style)
+    where
+        Renderer: renderer::Renderer,
+        Renderer::Theme: StyleSheet;
+
+    /// Creates the overlay showing the menus at `position`.
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        position: Point,
+        item_width: ItemWidth,
+        item_height: ItemHeight,
+    ) -> overlay::Element<'b, Message, Renderer>;
+}
+
+/// Creates the [`ContextMenus`] showing the given items.
+pub fn context_menus<'a, Message, Renderer>(
+    items: Vec<MenuTree<'a, Message, Renderer>>,
+) -> Box<dyn ContextMenus<'a, Message, Renderer> + 'a>
+where
+    Message: 'a,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    // the items are the children of a single invisible root
+    let mut root = MenuTree::with_children(Space::new(Length::Shrink, Length::Shrink), items);
+    root.set_index();
+
+    Box::new(MenuTrees {
+        roots: vec![root],
+        style: <Renderer::Theme as StyleSheet>::Style::default(),
+    })
+}
+
+/// Opens the menus of the given widget tree.
+pub fn open(tree: &mut Tree) {
+    let state = tree.state.downcast_mut::<MenuBarState>();
+    state.reset();
+    state.open = true;
+}
+
+/// Returns true if the menus of the given widget tree are open.
+pub fn is_open(tree: &Tree) -> bool {
+    tree.state.downcast_ref::<MenuBarState>().open
+}
+
+/// The [`ContextMenus`] of a list of [`MenuTree`]s.
+struct MenuTrees<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    roots: Vec<MenuTree<'a, Message, Renderer>>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> ContextMenus<'a, Message, Renderer> for MenuTrees<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tree(&self) -> Tree {
+        Tree {
+            tag: tree::Tag::of::<MenuBarState>(),
+            state: tree::State::new(MenuBarState::default()),
+            children: self
+                .roots
+                .iter()
+                .map(|root| {
+                    let mut tree = Tree::empty();
+                    tree.children = root
+                        .flattern()
+                        .iter()
+                        .map(|mt| Tree::new(mt.item.as_widget()))
+                        .collect();
+                    tree
+                })
+                .collect(),
+        }
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        if tree.tag != tree::Tag::of::<MenuBarState>() {
+            *tree = self.tree();
+            return;
+        }
+
+        tree.children
+            .iter_mut()
+            .zip(self.roots.iter())
+            .for_each(|(t, root)| {
+                let flat = root
+                    .flattern()
+                    .iter()
+                    .map(|mt| mt.item.as_widget())
+                    .collect::<Vec<_>>();
+
+                t.diff_children(&flat);
+            });
+    }
+
+    fn set_style(&mut self, style: <Renderer::Theme as StyleSheet>::Style) {
+        self.style = style;
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        position: Point,
+        item_width: ItemWidth,
+        item_height: ItemHeight,
+    ) -> overlay::Element<'b, Message, Renderer> {
+        // the invisible root sits at the position where the context menu was opened
+        let bounds = Rectangle::new(position, Size::ZERO);
+
+        Menu {
+            tree,
+            menu_roots: &mut self.roots,
+            bounds_expand: 15,
+            close_condition: CloseCondition {
+                leave: false,
+                click_outside: true,
+                click_inside: true,
+            },
+            item_width,
+            item_height,
+            orientation: Orientation::Vertical,
+            open_delay: Duration::ZERO,
+            close_delay: Duration::ZERO,
+            safe_triangle: true,
+            is_context: true,
+            bar_bounds: bounds,
+            root_bounds_list: vec![bounds],
+            path_highlight: Some(PathHighlight::MenuActive),
+            style: &self.style,
+        }
+        .overlay()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{context_menus, is_open, open, MenuBarState};
+    use crate::native::menu::{ItemHeight, ItemWidth, MenuTree};
+    use crate::style::menu_bar::{Appearance, StyleSheet};
+    use iced_widget::{
+        core::{renderer::Null, widget::Tree, Layout, Point, Size, Vector},
+        Space,
+    };
+
+    impl StyleSheet for () {
+        type Style = ();
+
+        fn appearance(&self, _style: &Self::Style) -> Appearance {
+            Appearance::default()
+        }
+    }
+
+    fn item() -> MenuTree<'static, (), crate::Renderer> {
+        MenuTree::new(Space::with_width(0))
+    }
+
+    #[test]
+    fn menus_open_closed_and_keep_their_state_when_diffed() {
+        let menus = context_menus(vec![
+            item(),
+            MenuTree::with_children(Space::with_width(0), vec![item()]),
+        ]);
+
+        let mut tree = menus.tree();
+        // the invisible root holds all the items
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].children.len(), 4);
+        assert!(!is_open(&tree));
+
+        open(&mut tree);
+        menus.diff(&mut tree);
+        assert!(is_open(&tree));
+
+        // a tree of a different widget is replaced
+        let mut other = Tree::empty();
+        menus.diff(&mut other);
+        assert!(!is_open(&other));
+        assert_eq!(other.children[0].children.len(), 4);
+    }
+
+    #[test]
+    fn empty_menus_are_closed_instead_of_shown() {
+        let mut menus = context_menus::<(), Null>(Vec::new());
+        let mut tree = menus.tree();
+        open(&mut tree);
+
+        let mut overlay = menus.overlay(
+            &mut tree,
+            Point::ORIGIN,
+            ItemWidth::Uniform(150),
+            ItemHeight::Uniform(30),
+        );
+        let node = overlay.layout(&Null, Size::new(800.0, 600.0), Vector::ZERO);
+        assert!(overlay.overlay(Layout::new(&node), &Null).is_none());
+        drop(overlay);
+
+        assert!(!is_open(&tree));
+    }
+
+    #[test]
+    fn opened_menus_are_shown_after_their_layout() {
+        let mut menus = context_menus::<(), Null>(vec![
+            MenuTree::new(Space::with_width(0)),
+            MenuTree::new(Space::with_width(0)),
+        ]);
+        let mut tree = menus.tree();
+        open(&mut tree);
+
+        let mut overlay = menus.overlay(
+            &mut tree,
+            Point::ORIGIN,
+            ItemWidth::Uniform(150),
+            ItemHeight::Uniform(30),
+        );
+        let node = overlay.layout(&Null, Size::new(800.0, 600.0), Vector::ZERO);
+
+        // iced asks for nested overlays after the layout and again while
+        // drawing, which must open the menu exactly once
+        assert!(overlay.overlay(Layout::new(&node), &Null).is_none());
+        assert!(overlay.overlay(Layout::new(&node), &Null).is_none());
+        drop(overlay);
+
+        let state = tree.state.downcast_ref::<MenuBarState>();
+        assert!(state.open);
+        assert_eq!(state.menu_states.len(), 1);
+    }
+}
@@ -29,7 +29,6 @@ pub enum Axis {
     Horizontal,
 
     /// The vertical axis
-    #[allow(dead_code)]
     Vertical,
 }
 
@@ -1,19 +1,26 @@
 //! A widget that handles menu trees
 
 use super::menu_inner::{
-    CloseCondition, Direction, ItemHeight, ItemWidth, Menu, MenuState, PathHighlight,
+    activate_item, CloseCondition, Direction, ItemHeight, ItemWidth, Menu, MenuState, Orientation,
+    PathHighlight,
 };
 use super::menu_tree::MenuTree;
+use super::Shortcut;
+use crate::core::operation::{widget_id, StateOperation};
 use crate::style::menu_bar::StyleSheet;
 
 use iced_widget::core::{
     event,
+    keyboard::{self, KeyCode},
     layout::{Limits, Node},
     mouse::{self, Cursor},
-    overlay, renderer, touch,
-    widget::{tree, Tree},
-    Alignment, Clipboard, Color, Element, Layout, Length, Padding, Rectangle, Shell, Widget,
+    overlay, renderer, text, touch,
+    widget::{tree, Operation, Tree},
+    Alignment, Clipboard, Color, Element, Font, Layout, Length, Padding, Point, Rectangle, Shell,
+    Size, Widget,
 };
+use iced_widget::runtime::Command;
+use std::time::{Duration, Instant};
 
 pub(super) struct MenuBarState {
     pub(super) pressed: bool,
@@ -23,6 +30,20 @@ pub(super) struct MenuBarState {
     pub(super) horizontal_direction: Direction,
     pub(super) vertical_direction: Direction,
     pub(super) menu_states: Vec<MenuState>,
+    /// The root with keyboard focus, `None` when the keyboard is not used
+    pub(super) focused_root: Option<usize>,
+    /// Whether Alt was pressed without any other key since
+    pub(super) alt_pressed: bool,
+    /// The previous position of the cursor in overlay space
+    pub(super) last_cursor: Point,
+    /// When the cursor started to hover the active item of the last menu
+    pub(super) hover_start: Instant,
+    /// When the cursor left the menus that close after the close delay
+    pub(super) leave_start: Option<Instant>,
+    /// When the menus have to be updated for a pending delay
+    pub(super) hover_deadline: Option<Instant>,
+    /// The root whose menu was opened by a command and is not shown yet
+    pub(super) pending_root: Option<usize>,
 }
 impl MenuBarState {
     pub(super) fn get_trimmed_indices(&self) -> impl Iterator<Item = usize> + '_ {
@@ -35,8 +56,22 @@ impl MenuBarState {
     pub(super) fn reset(&mut self) {
         self.open = false;
         self.active_root = None;
+        self.focused_root = None;
+        self.leave_start = None;
+        self.hover_deadline = None;
+        self.pending_root = None;
         self.menu_states.clear();
     }
+
+    /// Focuses the first root with the keyboard or removes the focus
+    fn toggle_keyboard_focus(&mut self, root_count: usize) {
+        if self.focused_root.is_some() || self.active_root.is_some() {
+            self.reset();
+        } else if root_count > 0 {
+            self.open = true;
+            self.focused_root = Some(0);
+        }
+    }
 }
 impl Default for MenuBarState {
     fn default() -> Self {
@@ -48,12 +83,25 @@ impl Default for MenuBarState {
             horizontal_direction: Direction::Positive,
             vertical_direction: Direction::Positive,
             menu_states: Vec::new(),
+            focused_root: None,
+            alt_pressed: false,
+            last_cursor: Point::ORIGIN,
+            hover_start: Instant::now(),
+            leave_start: None,
+            hover_deadline: None,
+            pending_root: None,
         }
     }
 }
 
 /// A `MenuBar` collects `MenuTree`s and handles
 /// all the layout, event processing and drawing
+///
+/// Pressing and releasing Alt or pressing F10 moves the keyboard focus to the menu bar.
+/// Left and Right move between the roots and Down or Enter opens a menu, inside of
+/// menus Up and Down move between the items, Right and Left open and close submenus,
+/// Enter activates an item and Escape closes one level.
+/// In a vertical [`MenuBar`] Up and Down move between the roots and Right opens a menu
 #[allow(missing_debug_implementations)]
 pub struct MenuBar<'a, Message, Renderer = crate::Renderer>
 where
@@ -68,9 +116,14 @@ where
     close_condition: CloseCondition,
     item_width: ItemWidth,
     item_height: ItemHeight,
+    orientation: Orientation,
+    open_delay: Duration,
+    close_delay: Duration,
+    safe_triangle: bool,
     path_highlight: Option<PathHighlight>,
     menu_roots: Vec<MenuTree<'a, Message, Renderer>>,
     style: <Renderer::Theme as StyleSheet>::Style,
+    id: Option<Id>,
 }
 impl<'a, Message, Renderer> MenuBar<'a, Message, Renderer>
 where
@@ -96,9 +149,14 @@ where
             },
             item_width: ItemWidth::Uniform(150),
             item_height: ItemHeight::Uniform(30),
+            orientation: Orientation::Horizontal,
+            open_delay: Duration::ZERO,
+            close_delay: Duration::ZERO,
+            safe_triangle: true,
             path_highlight: Some(PathHighlight::MenuActive),
             menu_roots,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
+            id: None,
         }
     }
 
@@ -162,6 +220,43 @@ where
         self
     }
 
+    /// Sets the [`Orientation`] of the [`MenuBar`]
+    ///
+    /// The roots of a vertical [`MenuBar`] are stacked from top to bottom
+    /// and their menus open to the side instead of downward
+    #[must_use]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets how long the cursor has to rest on an item before its submenu opens
+    #[must_use]
+    pub fn open_delay(mut self, delay: Duration) -> Self {
+        self.open_delay = delay;
+        self
+    }
+
+    /// Sets how long menus stay open after the cursor left them
+    ///
+    /// Moving the cursor back into a menu before the delay runs out keeps it open
+    #[must_use]
+    pub fn close_delay(mut self, delay: Duration) -> Self {
+        self.close_delay = delay;
+        self
+    }
+
+    /// Sets whether the open submenu is kept while the cursor moves towards it
+    ///
+    /// While the cursor moves inside of the triangle between its previous position
+    /// and the edge of the submenu it can cross other items of the parent menu
+    /// without activating them, which makes diagonal movements into submenus possible
+    #[must_use]
+    pub fn safe_triangle(mut self, enabled: bool) -> Self {
+        self.safe_triangle = enabled;
+        self
+    }
+
     /// Sets the method for drawing path highlight
     #[must_use]
     pub fn path_highlight(mut self, path_highlight: Option<PathHighlight>) -> Self {
@@ -175,10 +270,18 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets the [`Id`] of the [`MenuBar`] to open, close and query its menus
+    /// with [`open`], [`close`] and [`is_open`]
+    #[must_use]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
 }
 impl<'a, Message, Renderer> Widget<Message, Renderer> for MenuBar<'a, Message, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
     Renderer::Theme: StyleSheet,
 {
     fn width(&self) -> Length {
@@ -251,6 +354,17 @@ where
             .collect()
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<MenuBarState>();
+        operation.custom(state, self.id.as_ref().map(|id| &id.0));
+    }
+
     fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
         use super::flex;
 
@@ -260,8 +374,13 @@ where
             .iter()
             .map(|root| &root.item)
             .collect::<Vec<_>>();
+        let axis = match self.orientation {
+            Orientation::Horizontal => flex::Axis::Horizontal,
+            Orientation::Vertical => flex::Axis::Vertical,
+        };
+
         flex::resolve(
-            &flex::Axis::Horizontal,
+            &axis,
             renderer,
             &limits,
             self.padding,
@@ -282,10 +401,37 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
-        use event::Event::{Mouse, Touch};
-        use mouse::{Button::Left, Event::ButtonReleased};
+        use event::Event::{Keyboard, Mouse, Touch};
+        use keyboard::Event::{KeyPressed, KeyReleased};
+        use mouse::{
+            Button::Left,
+            Event::{ButtonPressed, ButtonReleased},
+        };
         use touch::Event::{FingerLifted, FingerLost};
 
+        if let Keyboard(KeyPressed {
+            key_code,
+            modifiers,
+        }) = event
+        {
+            let shortcut_status = process_shortcut_events(
+                &mut self.menu_roots,
+                tree,
+                key_code,
+                modifiers,
+                self.item_width,
+                self.item_height,
+                renderer,
+                clipboard,
+                shell,
+            );
+
+            if shortcut_status == event::Status::Captured {
+                tree.state.downcast_mut::<MenuBarState>().reset();
+                return shortcut_status;
+            }
+        }
+
         let root_status = process_root_events(
             &mut self.menu_roots,
             view_cursor,
@@ -307,6 +453,27 @@ where
                     state.open = true;
                 }
             }
+            Keyboard(KeyPressed {
+                key_code: KeyCode::LAlt | KeyCode::RAlt,
+                ..
+            }) => state.alt_pressed = true,
+            Keyboard(KeyPressed {
+                key_code: KeyCode::F10,
+                ..
+            }) => {
+                state.alt_pressed = false;
+                state.toggle_keyboard_focus(self.menu_roots.len());
+                return event::Status::Captured;
+            }
+            Keyboard(KeyReleased {
+                key_code: KeyCode::LAlt | KeyCode::RAlt,
+                ..
+            }) if state.alt_pressed => {
+                state.alt_pressed = false;
+                state.toggle_keyboard_focus(self.menu_roots.len());
+                return event::Status::Captured;
+            }
+            Keyboard(KeyPressed { .. }) | Mouse(ButtonPressed(_)) => state.alt_pressed = false,
             _ => (),
         }
         root_status
@@ -365,6 +532,20 @@ where
                     viewport,
                 );
             });
+
+        // draw keyboard focus
+        if let Some(focused) = state.focused_root {
+            let styling = theme.appearance(&self.style);
+            if let Some(focused_layout) = layout.children().nth(focused) {
+                let focus_quad = renderer::Quad {
+                    bounds: focused_layout.bounds(),
+                    border_radius: styling.border_radius.into(),
+                    border_width: 1.0,
+                    border_color: styling.keyboard_focus,
+                };
+                renderer.fill_quad(focus_quad, Color::TRANSPARENT);
+            }
+        }
     }
 
     fn overlay<'b>(
@@ -386,6 +567,11 @@ where
                 close_condition: self.close_condition,
                 item_width: self.item_width,
                 item_height: self.item_height,
+                orientation: self.orientation,
+                open_delay: self.open_delay,
+                close_delay: self.close_delay,
+                safe_triangle: self.safe_triangle,
+                is_context: false,
                 bar_bounds: layout.bounds(),
                 root_bounds_list: layout.children().map(|lo| lo.bounds()).collect(),
                 path_highlight: self.path_highlight,
@@ -398,7 +584,7 @@ where
 impl<'a, Message, Renderer> From<MenuBar<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
     Message: 'a,
-    Renderer: 'a + renderer::Renderer,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = Font>,
     Renderer::Theme: StyleSheet,
 {
     fn from(value: MenuBar<'a, Message, Renderer>) -> Self {
@@ -406,6 +592,46 @@ where
     }
 }
 
+widget_id! {
+    /// The identifier of a [`MenuBar`]
+    Id
+}
+
+/// Produces a [`Command`] that opens the menu of the root at `root`
+/// of the [`MenuBar`] with the given [`Id`]
+///
+/// The menu stays open until it is closed with the keyboard, a click or [`close`]
+pub fn open<Message: 'static>(id: Id, root: usize) -> Command<Message> {
+    Command::widget(StateOperation::new(id, move |state: &mut MenuBarState| {
+        state.reset();
+        state.open = true;
+        state.focused_root = Some(root);
+        state.pending_root = Some(root);
+        None
+    }))
+}
+
+/// Produces a [`Command`] that closes the menus of the [`MenuBar`] with the given [`Id`]
+pub fn close<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(StateOperation::new(id, |state: &mut MenuBarState| {
+        state.reset();
+        None
+    }))
+}
+
+/// Produces a [`Command`] that reports whether a menu of the [`MenuBar`]
+/// with the given [`Id`] is open
+pub fn is_open<Message: 'static>(
+    id: Id,
+    f: impl Fn(bool) -> Message + 'static,
+) -> Command<Message> {
+    Command::widget(StateOperation::new(id, move |state: &mut MenuBarState| {
+        Some(f(
+            !state.menu_states.is_empty() || state.pending_root.is_some()
+        ))
+    }))
+}
+
 #[allow(unused_results, clippy::too_many_arguments)]
 fn process_root_events<Message, Renderer>(
     menu_roots: &mut [MenuTree<'_, Message, Renderer>],
@@ -440,3 +666,110 @@ where
         })
         .fold(event::Status::Ignored, event::Status::merge)
 }
+
+/// Activates the item whose shortcut is triggered by the pressed keys
+#[allow(clippy::too_many_arguments)]
+fn process_shortcut_events<Message, Renderer>(
+    menu_roots: &mut [MenuTree<'_, Message, Renderer>],
+    tree: &mut Tree,
+    key_code: KeyCode,
+    modifiers: keyboard::Modifiers,
+    item_width: ItemWidth,
+    item_height: ItemHeight,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+) -> event::Status
+where
+    Renderer: renderer::Renderer,
+{
+    if !Shortcut::can_trigger(key_code, modifiers) {
+        return event::Status::Ignored;
+    }
+
+    for (root, t) in menu_roots.iter_mut().zip(&mut tree.children) {
+        if let Some(item) = root.find_shortcut(key_code, modifiers) {
+            let width = match item_width {
+                ItemWidth::Uniform(u) => u,
+                ItemWidth::Static(s) => item.width.unwrap_or(s),
+            };
+            let height = match item_height {
+                ItemHeight::Uniform(u) => u,
+                ItemHeight::Static(s) | ItemHeight::Flex(s) => item.height.unwrap_or(s),
+            };
+            let limits = Limits::new(Size::ZERO, Size::new(f32::from(width), f32::from(height)));
+            let node = item.item.as_widget().layout(renderer, &limits);
+            let index = item.index;
+
+            activate_item(
+                item,
+                &mut t.children[index],
+                &node,
+                renderer,
+                clipboard,
+                shell,
+            );
+            return event::Status::Captured;
+        }
+    }
+
+    event::Status::Ignored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{close, is_open, open, Id, MenuBarState};
+    use iced_widget::{
+        core::widget::operation::Outcome,
+        runtime::{command::Action, Command},
+    };
+
+    /// Runs the operations of `command` on the `state` of the menu bar with
+    /// the given identifier and returns their output.
+    fn run<T>(command: Command<T>, state: &mut MenuBarState, id: &'static str) -> Option<T> {
+        let mut output = None;
+
+        for action in command.actions() {
+            if let Action::Widget(mut operation) = action {
+                operation.custom(state, Some(&Id::new(id).into()));
+                if let Outcome::Some(value) = operation.finish() {
+                    output = Some(value);
+                }
+            }
+        }
+
+        output
+    }
+
+    #[test]
+    fn operations_only_change_the_menu_bar_with_their_id() {
+        let mut state = MenuBarState::default();
+
+        let _ = run(open::<()>(Id::new("other"), 1), &mut state, "menu_bar");
+        assert!(!state.open);
+
+        let _ = run(open::<()>(Id::new("menu_bar"), 1), &mut state, "menu_bar");
+        assert!(state.open);
+        assert_eq!(state.pending_root, Some(1));
+
+        assert_eq!(
+            run(
+                is_open(Id::new("menu_bar"), |open| open),
+                &mut state,
+                "menu_bar"
+            ),
+            Some(true)
+        );
+
+        let _ = run(close::<()>(Id::new("menu_bar")), &mut state, "menu_bar");
+        assert!(!state.open);
+        assert_eq!(
+            run(
+                is_open(Id::new("menu_bar"), |open| open),
+                &mut state,
+                "menu_bar"
+            ),
+            Some(false)
+        );
+    }
+}
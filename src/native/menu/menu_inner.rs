@@ -5,13 +5,22 @@ use super::menu_tree::MenuTree;
 use crate::style::menu_bar::StyleSheet;
 
 use iced_widget::core::{
+    alignment::{Horizontal, Vertical},
     event,
+    keyboard::{self, KeyCode},
     layout::{Limits, Node},
     mouse::{self, Cursor},
-    overlay, renderer, touch,
+    overlay, renderer, text, touch,
     widget::Tree,
-    Clipboard, Color, Layout, Padding, Point, Rectangle, Shell, Size, Vector,
+    window, Clipboard, Color, Font, Layout, Padding, Point, Rectangle, Shell, Size, Vector,
 };
+use std::time::{Duration, Instant};
+
+/// The space on both sides of a shortcut label
+const SHORTCUT_PADDING: f32 = 10.0;
+
+/// How long the cursor may rest inside of the safe triangle before the item under it is activated
+const SAFE_TRIANGLE_TIMEOUT: Duration = Duration::from_millis(300);
 
 /// The condition of when to close a menu
 #[derive(Debug, Clone, Copy)]
@@ -49,7 +58,24 @@ pub enum ItemHeight {
     /// the default value will be used instead,
     /// which is the value of the Static variant
     Static(u16),
-    // TODO: Flex,
+    /// Flex tries to use the height value of each menu tree,
+    /// if that value is None,
+    /// the natural height of the item will be measured by laying it out
+    /// with the width of the menu it's in,
+    /// items that fill the available height fall back to
+    /// the value of the Flex variant
+    Flex(u16),
+}
+
+/// The direction in which the roots of a menu bar are laid out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Orientation {
+    /// Lay the roots out from left to right and open their menus downward
+    #[default]
+    Horizontal,
+    /// Stack the roots from top to bottom and open their menus to the side,
+    /// like a sidebar or a tool palette
+    Vertical,
 }
 
 /// Methods for drawing path highlight
@@ -87,6 +113,35 @@ struct Aod {
     vertical_direction: Direction,
 }
 impl Aod {
+    /// The open direction of the menu of a root in the menu bar
+    ///
+    /// The menus of a vertical menu bar open to the side just like submenus
+    fn root(state: &MenuBarState, orientation: Orientation) -> Self {
+        match orientation {
+            Orientation::Horizontal => Self {
+                horizontal: true,
+                vertical: true,
+                horizontal_overlap: true,
+                vertical_overlap: false,
+                horizontal_direction: state.horizontal_direction,
+                vertical_direction: state.vertical_direction,
+            },
+            Orientation::Vertical => Self::submenu(state),
+        }
+    }
+
+    /// The open direction of a submenu
+    fn submenu(state: &MenuBarState) -> Self {
+        Self {
+            horizontal: true,
+            vertical: true,
+            horizontal_overlap: false,
+            vertical_overlap: true,
+            horizontal_direction: state.horizontal_direction,
+            vertical_direction: state.vertical_direction,
+        }
+    }
+
     fn adaptive(
         parent_pos: f32,
         parent_size: f32,
@@ -209,6 +264,7 @@ struct MenuSlice {
 /// Menu bounds in overlay space
 struct MenuBounds {
     child_positions: Vec<f32>,
+    child_heights: Vec<f32>,
     children_bounds: Rectangle,
     parent_bounds: Rectangle,
     check_bounds: Rectangle,
@@ -224,11 +280,14 @@ impl MenuBounds {
         aod: &Aod,
         bounds_expand: u16,
         parent_bounds: Rectangle,
+        renderer: &Renderer,
     ) -> Self
     where
-        Renderer: renderer::Renderer,
+        Renderer: renderer::Renderer + text::Renderer<Font = Font>,
     {
-        let children_size = get_children_size(menu_tree, item_width, item_height);
+        let width = get_children_width(menu_tree, item_width, renderer);
+        let child_heights = get_child_heights(menu_tree, width, item_height, renderer);
+        let children_size = Size::new(width, child_heights.iter().sum());
 
         // viewport space parent bounds
         let view_parent_bounds = parent_bounds + overlay_offset;
@@ -238,16 +297,52 @@ impl MenuBounds {
             aod.point(view_parent_bounds, children_size, viewport_size) - overlay_offset;
 
         let children_bounds = Rectangle::new(children_position, children_size);
-        let child_positions = get_child_positions(menu_tree, item_height);
+        let child_positions = get_child_positions(&child_heights);
         let check_bounds = pad_rectangle(children_bounds, [bounds_expand; 4].into());
 
         Self {
             child_positions,
+            child_heights,
             children_bounds,
             parent_bounds,
             check_bounds,
         }
     }
+
+    /// Returns true if the cursor moved from `previous` to `cursor` towards the menu,
+    /// i.e. `cursor` lies inside of the triangle between `previous`
+    /// and the edge of the menu facing its parent
+    fn in_safe_triangle(&self, previous: Point, cursor: Point) -> bool {
+        let parent = self.parent_bounds;
+        let children = self.children_bounds;
+
+        if previous == cursor || parent.contains(cursor) || children.contains(cursor) {
+            return false;
+        }
+
+        let (left, right) = (children.x, children.x + children.width);
+        let (top, bottom) = (children.y, children.y + children.height);
+
+        let (a, b) = if left >= parent.x + parent.width {
+            (Point::new(left, top), Point::new(left, bottom))
+        } else if right <= parent.x {
+            (Point::new(right, top), Point::new(right, bottom))
+        } else if top >= parent.y + parent.height {
+            (Point::new(left, top), Point::new(right, top))
+        } else if bottom <= parent.y {
+            (Point::new(left, bottom), Point::new(right, bottom))
+        } else {
+            return false;
+        };
+
+        let cross =
+            |p: Point, q: Point, r: Point| (q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x);
+        let d1 = cross(previous, a, cursor);
+        let d2 = cross(a, b, cursor);
+        let d3 = cross(b, previous, cursor);
+
+        (d1 > 0.0 && d2 > 0.0 && d3 > 0.0) || (d1 < 0.0 && d2 < 0.0 && d3 < 0.0)
+    }
 }
 
 pub(super) struct MenuState {
@@ -260,7 +355,6 @@ impl MenuState {
         &self,
         overlay_offset: Vector,
         slice: MenuSlice,
-        item_height: ItemHeight,
         renderer: &Renderer,
         menu_tree: &MenuTree<'_, Message, Renderer>,
     ) -> Node
@@ -284,10 +378,11 @@ impl MenuState {
 
         let child_nodes = self.menu_bounds.child_positions[start_index..=end_index]
             .iter()
+            .zip(self.menu_bounds.child_heights[start_index..=end_index].iter())
             .zip(menu_tree.children[start_index..=end_index].iter())
-            .map(|(cp, mt)| {
+            .map(|((cp, ch), mt)| {
                 let mut position = *cp;
-                let mut size = get_item_size(mt, children_bounds.width, item_height);
+                let mut size = Size::new(children_bounds.width, *ch);
 
                 if position < lower_bound_rel && (position + size.height) > lower_bound_rel {
                     size.height = position + size.height - lower_bound_rel;
@@ -314,7 +409,6 @@ impl MenuState {
         &self,
         overlay_offset: Vector,
         index: usize,
-        item_height: ItemHeight,
         renderer: &Renderer,
         menu_tree: &MenuTree<'_, Message, Renderer>,
     ) -> Node
@@ -327,7 +421,7 @@ impl MenuState {
         let position = self.menu_bounds.child_positions[index];
         let limits = Limits::new(
             Size::ZERO,
-            get_item_size(menu_tree, children_bounds.width, item_height),
+            Size::new(children_bounds.width, self.menu_bounds.child_heights[index]),
         );
         let parent_offset = children_bounds.position() - Point::ORIGIN;
         let mut node = menu_tree.item.as_widget().layout(renderer, &limits);
@@ -338,6 +432,73 @@ impl MenuState {
         node
     }
 
+    /// Scrolls the menu so that the item at `index` lies inside of the viewport
+    fn scroll_into_view(&mut self, index: usize, viewport_size: Size, overlay_offset: Vector) {
+        // viewport space children bounds
+        let children_bounds = self.menu_bounds.children_bounds + overlay_offset;
+
+        let top = children_bounds.y + self.menu_bounds.child_positions[index] + self.scroll_offset;
+        let bottom = top + self.menu_bounds.child_heights[index];
+
+        if top < 0.0 {
+            self.scroll_offset -= top;
+        } else if bottom > viewport_size.height {
+            self.scroll_offset -= bottom - viewport_size.height;
+        }
+
+        let max_offset = (0.0 - children_bounds.y).max(0.0);
+        let min_offset =
+            (viewport_size.height - (children_bounds.y + children_bounds.height)).min(0.0);
+        self.scroll_offset = self.scroll_offset.clamp(min_offset, max_offset);
+    }
+
+    /// Creates the state of the submenu of the item at `index`
+    #[allow(clippy::too_many_arguments)]
+    fn submenu<Message, Renderer>(
+        &self,
+        index: usize,
+        item: &MenuTree<'_, Message, Renderer>,
+        item_width: ItemWidth,
+        item_height: ItemHeight,
+        bounds_expand: u16,
+        aod: &Aod,
+        viewport_size: Size,
+        overlay_offset: Vector,
+        renderer: &Renderer,
+    ) -> Self
+    where
+        Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    {
+        let item_position = Point::new(
+            0.0,
+            self.menu_bounds.child_positions[index] + self.scroll_offset,
+        );
+        let item_size = Size::new(
+            self.menu_bounds.children_bounds.width,
+            self.menu_bounds.child_heights[index],
+        );
+
+        // overlay space item bounds
+        let item_bounds = Rectangle::new(item_position, item_size)
+            + (self.menu_bounds.children_bounds.position() - Point::ORIGIN);
+
+        Self {
+            index: None,
+            scroll_offset: 0.0,
+            menu_bounds: MenuBounds::new(
+                item,
+                item_width,
+                item_height,
+                viewport_size,
+                overlay_offset,
+                aod,
+                bounds_expand,
+                item_bounds,
+                renderer,
+            ),
+        }
+    }
+
     fn slice<Message, Renderer>(
         &self,
         viewport_size: Size,
@@ -381,6 +542,19 @@ impl MenuState {
                 )
                 .min(max_index);
 
+                (start_index, end_index)
+            }
+            ItemHeight::Flex(_) => {
+                let positions = &self.menu_bounds.child_positions;
+
+                let start_index = positions
+                    .partition_point(|&p| p <= lower_bound_rel)
+                    .saturating_sub(1);
+                let end_index = positions
+                    .partition_point(|&p| p <= upper_bound_rel)
+                    .saturating_sub(1)
+                    .min(max_index);
+
                 (start_index, end_index)
             }
         };
@@ -405,6 +579,13 @@ where
     pub(super) close_condition: CloseCondition,
     pub(super) item_width: ItemWidth,
     pub(super) item_height: ItemHeight,
+    pub(super) orientation: Orientation,
+    pub(super) open_delay: Duration,
+    pub(super) close_delay: Duration,
+    pub(super) safe_triangle: bool,
+    /// Whether the menus belong to a context menu with a single invisible root
+    /// that opens as soon as the overlay is shown and closes with its menu
+    pub(super) is_context: bool,
     pub(super) bar_bounds: Rectangle,
     pub(super) root_bounds_list: Vec<Rectangle>,
     pub(super) path_highlight: Option<PathHighlight>,
@@ -412,17 +593,56 @@ where
 }
 impl<'a, 'b, Message, Renderer> Menu<'a, 'b, Message, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
     Renderer::Theme: StyleSheet,
 {
     pub(super) fn overlay(self) -> overlay::Element<'b, Message, Renderer> {
         overlay::Element::new(Point::ORIGIN, Box::new(self))
     }
+
+    /// Opens the menu of a context menu or of a command that is not open yet
+    fn open_pending_menu(&mut self, viewport: Rectangle, renderer: &Renderer) {
+        let viewport_size = viewport.size();
+        let overlay_offset = Point::ORIGIN - viewport.position();
+        let state = self.tree.state.downcast_mut::<MenuBarState>();
+
+        if !state.open {
+            return;
+        }
+
+        if self.is_context && state.menu_states.is_empty() {
+            // an empty context menu has nothing to show
+            if self.menu_roots[0].children.is_empty() {
+                state.reset();
+                return;
+            }
+            open_root_menu(self, 0, None, viewport_size, overlay_offset, renderer);
+        }
+
+        // a menu opened by a command
+        let pending_root = self
+            .tree
+            .state
+            .downcast_mut::<MenuBarState>()
+            .pending_root
+            .take();
+        if let Some(root) = pending_root {
+            if self
+                .menu_roots
+                .get(root)
+                .is_some_and(|root| !root.children.is_empty())
+            {
+                open_root_menu(self, root, Some(0), viewport_size, overlay_offset, renderer);
+            } else {
+                self.tree.state.downcast_mut::<MenuBarState>().reset();
+            }
+        }
+    }
 }
 impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
     for Menu<'a, 'b, Message, Renderer>
 where
-    Renderer: renderer::Renderer,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
     Renderer::Theme: StyleSheet,
 {
     fn layout(&self, _renderer: &Renderer, bounds: Size, position: Point) -> Node {
@@ -430,6 +650,21 @@ where
         Node::new(bounds).translate(Point::ORIGIN - position)
     }
 
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Renderer>> {
+        // Overlay::layout only borrows the menus immutably, so the menus opened
+        // by a command or a context menu are opened here instead: iced calls this
+        // right after the layout of the overlay, before it is drawn or receives
+        // events, so they are drawn right away. iced calls it again while drawing
+        // and handling events, which is harmless as a pending menu is only
+        // opened once.
+        self.open_pending_menu(layout.bounds(), renderer);
+        None
+    }
+
     fn on_event(
         &mut self,
         event: event::Event,
@@ -440,7 +675,7 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         use event::{
-            Event::{Mouse, Touch},
+            Event::{Keyboard, Mouse, Touch, Window},
             Status::{Captured, Ignored},
         };
         use mouse::{
@@ -458,10 +693,11 @@ where
         let overlay_offset = Point::ORIGIN - viewport.position();
         let overlay_cursor = view_cursor.position().unwrap_or_default() - overlay_offset;
 
+        self.open_pending_menu(viewport, renderer);
+
         let menu_status = process_menu_events(
             self.tree,
             self.menu_roots,
-            self.item_height,
             event.clone(),
             view_cursor,
             renderer,
@@ -470,15 +706,30 @@ where
             overlay_offset,
         );
 
-        init_root_menu(
-            self,
-            overlay_cursor,
-            viewport_size,
-            overlay_offset,
-            self.bar_bounds,
-        );
+        if !matches!(event, Keyboard(_)) {
+            init_root_menu(
+                self,
+                overlay_cursor,
+                viewport_size,
+                overlay_offset,
+                self.bar_bounds,
+                renderer,
+            );
+        }
+
+        let status = match event {
+            Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if menu_status == Ignored => {
+                process_keyboard_events(
+                    self,
+                    key_code,
+                    viewport_size,
+                    overlay_offset,
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            }
 
-        match event {
             Mouse(WheelScrolled { delta }) => {
                 process_scroll_events(self, delta, overlay_cursor, viewport_size, overlay_offset)
                     .merge(menu_status)
@@ -500,10 +751,32 @@ where
                     overlay_offset,
                     view_cursor,
                     overlay_cursor,
+                    renderer,
+                    Instant::now(),
+                    shell,
                 )
                 .merge(menu_status)
             }
 
+            // a delay ran out, update the menus for the resting cursor
+            Window(window::Event::RedrawRequested(now)) => {
+                let state = self.tree.state.downcast_mut::<MenuBarState>();
+                if state.hover_deadline.is_some_and(|deadline| now >= deadline) {
+                    state.hover_deadline = None;
+                    let _ = process_overlay_events(
+                        self,
+                        viewport_size,
+                        overlay_offset,
+                        view_cursor,
+                        overlay_cursor,
+                        renderer,
+                        now,
+                        shell,
+                    );
+                }
+                menu_status
+            }
+
             Mouse(ButtonReleased(Left)) | Touch(FingerLifted { .. }) => {
                 let state = self.tree.state.downcast_mut::<MenuBarState>();
                 state.pressed = false;
@@ -542,7 +815,15 @@ where
             }
 
             _ => menu_status,
+        };
+
+        // a context menu closes together with its menu
+        let state = self.tree.state.downcast_mut::<MenuBarState>();
+        if self.is_context && state.menu_states.is_empty() {
+            state.reset();
         }
+
+        status
     }
 
     #[allow(unused_results)]
@@ -582,6 +863,9 @@ where
                     PathHighlight::MenuActive => i < state.menu_states.len() - 1,
                 });
 
+                // only the last menu shows the keyboard focus
+                let draw_focus = state.focused_root.is_some() && i == state.menu_states.len() - 1;
+
                 // react only to the last menu
                 let view_cursor = if i == state.menu_states.len() - 1 {
                     view_cursor
@@ -597,8 +881,7 @@ where
                     let end_index = slice.end_index;
 
                     // calc layout
-                    let children_node =
-                        ms.layout(overlay_offset, slice, self.item_height, r, menu_root);
+                    let children_node = ms.layout(overlay_offset, slice, r, menu_root);
                     let children_layout = Layout::new(&children_node);
                     let children_bounds = children_layout.bounds();
 
@@ -643,7 +926,44 @@ where
                                 view_cursor,
                                 &children_layout.bounds(),
                             );
+
+                            // draw shortcut label
+                            if let Some(shortcut) = mt.shortcut {
+                                let bounds = clo.bounds();
+                                r.fill_text(text::Text {
+                                    content: &shortcut.to_string(),
+                                    bounds: Rectangle {
+                                        x: bounds.x + bounds.width - SHORTCUT_PADDING,
+                                        y: bounds.center_y(),
+                                        ..bounds
+                                    },
+                                    size: r.default_size(),
+                                    line_height: text::LineHeight::default(),
+                                    color: styling.shortcut,
+                                    font: r.default_font(),
+                                    horizontal_alignment: Horizontal::Right,
+                                    vertical_alignment: Vertical::Center,
+                                    shaping: text::Shaping::Basic,
+                                });
+                            }
                         });
+
+                    // draw keyboard focus
+                    if let (true, Some(active)) = (draw_focus, ms.index) {
+                        if let Some(active_layout) = (start_index..=end_index)
+                            .contains(&active)
+                            .then(|| children_layout.children().nth(active - start_index))
+                            .flatten()
+                        {
+                            let focus_quad = renderer::Quad {
+                                bounds: active_layout.bounds(),
+                                border_radius: styling.border_radius.into(),
+                                border_width: 1.0,
+                                border_color: styling.keyboard_focus,
+                            };
+                            r.fill_quad(focus_quad, Color::TRANSPARENT);
+                        }
+                    }
                 };
 
                 renderer.with_layer(render_bounds, draw_menu);
@@ -670,72 +990,287 @@ fn init_root_menu<Message, Renderer>(
     viewport_size: Size,
     overlay_offset: Vector,
     bar_bounds: Rectangle,
+    renderer: &Renderer,
 ) where
-    Renderer: renderer::Renderer,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
     Renderer::Theme: StyleSheet,
 {
-    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+    let state = menu.tree.state.downcast_ref::<MenuBarState>();
     if !(state.menu_states.is_empty() && bar_bounds.contains(overlay_cursor)) {
         return;
     }
 
-    for (i, (&root_bounds, mt)) in menu
+    let root = menu
         .root_bounds_list
         .iter()
         .zip(menu.menu_roots.iter())
-        .enumerate()
+        .position(|(root_bounds, mt)| {
+            !mt.children.is_empty() && root_bounds.contains(overlay_cursor)
+        });
+
+    if let Some(root) = root {
+        open_root_menu(menu, root, None, viewport_size, overlay_offset, renderer);
+    }
+}
+
+/// Opens the menu of a root and sets its active item
+fn open_root_menu<Message, Renderer>(
+    menu: &mut Menu<'_, '_, Message, Renderer>,
+    root: usize,
+    index: Option<usize>,
+    viewport_size: Size,
+    overlay_offset: Vector,
+    renderer: &Renderer,
+) where
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    // the keyboard focus skips separators and disabled items
+    let index = index.and_then(|i| menu.menu_roots[root].focusable_child(i, true));
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+    let root_bounds = menu.root_bounds_list[root];
+
+    let view_center = viewport_size.width * 0.5;
+    let rb_center = root_bounds.center_x();
+
+    // context menus open to the right of the cursor if there's enough space
+    state.horizontal_direction = if rb_center > view_center && !menu.is_context {
+        Direction::Negative
+    } else {
+        Direction::Positive
+    };
+
+    let menu_bounds = MenuBounds::new(
+        &menu.menu_roots[root],
+        menu.item_width,
+        menu.item_height,
+        viewport_size,
+        overlay_offset,
+        &Aod::root(state, menu.orientation),
+        menu.bounds_expand,
+        root_bounds,
+        renderer,
+    );
+
+    state.active_root = Some(root);
+    state.menu_states.push(MenuState {
+        index,
+        scroll_offset: 0.0,
+        menu_bounds,
+    });
+}
+
+/// Sends a click to the widget of an item
+pub(super) fn activate_item<Message, Renderer>(
+    item: &mut MenuTree<'_, Message, Renderer>,
+    tree: &mut Tree,
+    node: &Node,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+) where
+    Renderer: renderer::Renderer,
+{
+    let layout = Layout::new(node);
+    let bounds = layout.bounds();
+    let cursor = Cursor::Available(bounds.center());
+
+    for event in [
+        mouse::Event::ButtonPressed(mouse::Button::Left),
+        mouse::Event::ButtonReleased(mouse::Button::Left),
+    ] {
+        let _ = item.item.as_widget_mut().on_event(
+            tree,
+            event::Event::Mouse(event),
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &bounds,
+        );
+    }
+}
+
+/// Handles the keys moving the keyboard focus through the menu bar and its menus
+#[allow(unused_results, clippy::too_many_arguments)]
+fn process_keyboard_events<Message, Renderer>(
+    menu: &mut Menu<'_, '_, Message, Renderer>,
+    key_code: KeyCode,
+    viewport_size: Size,
+    overlay_offset: Vector,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+) -> event::Status
+where
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    use event::Status::{Captured, Ignored};
+    use KeyCode::{Down, End, Enter, Escape, Home, Left, NumpadEnter, Right, Space, Up};
+
+    if !matches!(
+        key_code,
+        Down | End | Enter | Escape | Home | Left | NumpadEnter | Right | Space | Up
+    ) {
+        return Ignored;
+    }
+
+    let root_count = menu.menu_roots.len();
+    let state = menu.tree.state.downcast_mut::<MenuBarState>();
+    let Some(root) = state.active_root.or(state.focused_root) else {
+        return Ignored;
+    };
+    state.focused_root = Some(root);
+
+    // a submenu opened by hovering its parent has no active item yet
+    if state.menu_states.len() > 1
+        && state
+            .menu_states
+            .last()
+            .is_some_and(|ms| ms.index.is_none())
     {
-        if mt.children.is_empty() {
-            continue;
-        }
+        state.menu_states.pop();
+    }
 
-        if root_bounds.contains(overlay_cursor) {
-            let view_center = viewport_size.width * 0.5;
-            let rb_center = root_bounds.center_x();
+    let previous_root = (root + root_count - 1) % root_count;
+    let next_root = (root + 1) % root_count;
 
-            state.horizontal_direction = if rb_center > view_center {
-                Direction::Negative
-            } else {
-                Direction::Positive
-            };
+    // the keys moving along the menu bar
+    let vertical = menu.orientation == Orientation::Vertical;
+    let (previous_key, next_key) = if vertical { (Up, Down) } else { (Left, Right) };
 
-            let aod = Aod {
-                horizontal: true,
-                vertical: true,
-                horizontal_overlap: true,
-                vertical_overlap: false,
-                horizontal_direction: state.horizontal_direction,
-                vertical_direction: state.vertical_direction,
+    // only the menu bar is focused
+    if state.menu_states.is_empty() {
+        match key_code {
+            _ if key_code == previous_key => state.focused_root = Some(previous_root),
+            _ if key_code == next_key => state.focused_root = Some(next_root),
+            Down | Right | Enter | NumpadEnter | Space => {
+                if !menu.menu_roots[root].children.is_empty() {
+                    open_root_menu(menu, root, Some(0), viewport_size, overlay_offset, renderer);
+                } else if !matches!(key_code, Down | Right) {
+                    let bounds = menu.root_bounds_list[root] + overlay_offset;
+                    let item = &mut menu.menu_roots[root];
+                    let mut node = item
+                        .item
+                        .as_widget()
+                        .layout(renderer, &Limits::new(Size::ZERO, bounds.size()));
+                    node.move_to(bounds.position());
+
+                    let tree = &mut menu.tree.children[root].children[item.index];
+                    activate_item(item, tree, &node, renderer, clipboard, shell);
+                    menu.tree.state.downcast_mut::<MenuBarState>().reset();
+                }
+            }
+            Escape => state.reset(),
+            _ => {}
+        }
+        return Captured;
+    }
+
+    let last = state.menu_states.len() - 1;
+    let path = state.menu_states[..last]
+        .iter()
+        .map(|ms| ms.index.expect("missing active child index in menu"))
+        .collect::<Vec<_>>();
+    let active_menu = path
+        .iter()
+        .fold(&menu.menu_roots[root], |mt, &i| &mt.children[i]);
+    let count = active_menu.children.len();
+    let index = state.menu_states[last].index;
+    let has_submenu = index.is_some_and(|i| !active_menu.children[i].children.is_empty());
+
+    match key_code {
+        Up | Down | Home | End => {
+            // separators and disabled items are skipped
+            let (start, forward) = match (key_code, index) {
+                (Down, Some(i)) => (i + 1, true),
+                (Up, Some(i)) => (i + count - 1, false),
+                (End | Up, _) => (count - 1, false),
+                _ => (0, true),
             };
 
-            let menu_bounds = MenuBounds::new(
-                mt,
+            if let Some(new_index) = active_menu.focusable_child(start, forward) {
+                let menu_state = &mut state.menu_states[last];
+                menu_state.index = Some(new_index);
+                menu_state.scroll_into_view(new_index, viewport_size, overlay_offset);
+            }
+        }
+        Right | Enter | NumpadEnter | Space if has_submenu => {
+            let index = index.expect("missing active child index in menu");
+            let submenu = state.menu_states[last].submenu(
+                index,
+                &active_menu.children[index],
                 menu.item_width,
                 menu.item_height,
+                menu.bounds_expand,
+                &Aod::submenu(state),
                 viewport_size,
                 overlay_offset,
-                &aod,
-                menu.bounds_expand,
-                root_bounds,
+                renderer,
             );
-
-            state.active_root = Some(i);
-            state.menu_states.push(MenuState {
-                index: None,
-                scroll_offset: 0.0,
-                menu_bounds,
-            });
-
-            break;
+            state.menu_states.push(submenu);
+            state.menu_states[last + 1].index =
+                active_menu.children[index].focusable_child(0, true);
         }
+        Left | Escape if last > 0 => {
+            state.menu_states.pop();
+        }
+        Left | Right if !vertical => {
+            let new_root = if key_code == Left {
+                previous_root
+            } else {
+                next_root
+            };
+            state.menu_states.clear();
+            state.active_root = None;
+            state.focused_root = Some(new_root);
+
+            if !menu.menu_roots[new_root].children.is_empty() {
+                open_root_menu(
+                    menu,
+                    new_root,
+                    Some(0),
+                    viewport_size,
+                    overlay_offset,
+                    renderer,
+                );
+            }
+        }
+        // Left goes back to a vertical menu bar
+        Left | Escape => {
+            state.menu_states.clear();
+            state.active_root = None;
+        }
+        Enter | NumpadEnter | Space => {
+            if let Some(index) = index {
+                let node = state.menu_states[last].layout_single(
+                    overlay_offset,
+                    index,
+                    renderer,
+                    &active_menu.children[index],
+                );
+
+                let item = path
+                    .iter()
+                    .chain(std::iter::once(&index))
+                    .fold(&mut menu.menu_roots[root], |mt, &i| &mut mt.children[i]);
+                let tree = &mut menu.tree.children[root].children[item.index];
+                activate_item(item, tree, &node, renderer, clipboard, shell);
+                menu.tree.state.downcast_mut::<MenuBarState>().reset();
+            }
+        }
+        _ => {}
     }
+
+    Captured
 }
 
 #[allow(clippy::too_many_arguments)]
 fn process_menu_events<'b, Message, Renderer>(
     tree: &'b mut Tree,
     menu_roots: &'b mut [MenuTree<'_, Message, Renderer>],
-    item_height: ItemHeight,
     event: event::Event,
     view_cursor: Cursor,
     renderer: &Renderer,
@@ -769,7 +1304,6 @@ where
     let child_node = last_ms.layout_single(
         overlay_offset,
         last_ms.index.expect("missing index within menu state."),
-        item_height,
         renderer,
         mt,
     );
@@ -791,16 +1325,29 @@ where
     )
 }
 
-#[allow(unused_results)]
+/// Requests an update of the menus at `deadline`
+fn schedule_hover_update<Message>(
+    state: &mut MenuBarState,
+    deadline: Instant,
+    shell: &mut Shell<'_, Message>,
+) {
+    state.hover_deadline = Some(state.hover_deadline.map_or(deadline, |d| d.min(deadline)));
+    shell.request_redraw(window::RedrawRequest::At(deadline));
+}
+
+#[allow(unused_results, clippy::too_many_arguments)]
 fn process_overlay_events<Message, Renderer>(
     menu: &mut Menu<'_, '_, Message, Renderer>,
     viewport_size: Size,
     overlay_offset: Vector,
     view_cursor: Cursor,
     overlay_cursor: Point,
+    renderer: &Renderer,
+    now: Instant,
+    shell: &mut Shell<'_, Message>,
 ) -> event::Status
 where
-    Renderer: renderer::Renderer,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
     Renderer::Theme: StyleSheet,
 {
     use event::Status::{Captured, Ignored};
@@ -817,7 +1364,7 @@ where
     let state = menu.tree.state.downcast_mut::<MenuBarState>();
 
     let Some(active_root) = state.active_root else {
-        if !menu.bar_bounds.contains(overlay_cursor) {
+        if !menu.bar_bounds.contains(overlay_cursor) && state.focused_root.is_none() {
             state.reset();
         }
         return Ignored;
@@ -831,8 +1378,20 @@ where
     but I still want Widget::draw() to react to cursor movement */
     state.view_cursor = view_cursor;
 
+    // * keep the last menu while the cursor moves towards it
+    let previous_cursor = std::mem::replace(&mut state.last_cursor, overlay_cursor);
+    if menu.safe_triangle
+        && state.menu_states.last().is_some_and(|ms| {
+            ms.menu_bounds
+                .in_safe_triangle(previous_cursor, overlay_cursor)
+        })
+    {
+        schedule_hover_update(state, now + SAFE_TRIANGLE_TIMEOUT, shell);
+        return Captured;
+    }
+
     // * remove invalid menus
-    let mut prev_bounds = std::iter::once(menu.bar_bounds)
+    let prev_bounds = std::iter::once(menu.bar_bounds)
         .chain(
             state.menu_states[..state.menu_states.len().saturating_sub(1)]
                 .iter()
@@ -840,34 +1399,35 @@ where
         )
         .collect::<Vec<_>>();
 
-    if menu.close_condition.leave {
-        for i in (0..state.menu_states.len()).rev() {
+    // the number of menus that stay open
+    let keep = (0..state.menu_states.len())
+        .rev()
+        .find(|&i| {
             let mb = &state.menu_states[i].menu_bounds;
+            let outside_prev = prev_bounds[..=i]
+                .iter()
+                .all(|pvb| !pvb.contains(overlay_cursor));
 
-            if mb.parent_bounds.contains(overlay_cursor)
+            mb.parent_bounds.contains(overlay_cursor)
                 || mb.children_bounds.contains(overlay_cursor)
-                || (mb.check_bounds.contains(overlay_cursor)
-                    && prev_bounds.iter().all(|pvb| !pvb.contains(overlay_cursor)))
-            {
-                break;
-            }
-            prev_bounds.pop();
-            state.menu_states.pop();
-        }
-    } else {
-        for i in (0..state.menu_states.len()).rev() {
-            let mb = &state.menu_states[i].menu_bounds;
+                // menus opened by the keyboard or a command don't close on leave
+                || if menu.close_condition.leave && state.focused_root.is_none() {
+                    mb.check_bounds.contains(overlay_cursor) && outside_prev
+                } else {
+                    outside_prev
+                }
+        })
+        .map_or(0, |i| i + 1);
 
-            if mb.parent_bounds.contains(overlay_cursor)
-                || mb.children_bounds.contains(overlay_cursor)
-                || prev_bounds.iter().all(|pvb| !pvb.contains(overlay_cursor))
-            {
-                break;
-            }
-            prev_bounds.pop();
-            state.menu_states.pop();
+    if keep < state.menu_states.len() {
+        let leave_start = *state.leave_start.get_or_insert(now);
+        if now < leave_start + menu.close_delay {
+            schedule_hover_update(state, leave_start + menu.close_delay, shell);
+            return Captured;
         }
+        state.menu_states.truncate(keep);
     }
+    state.leave_start = None;
 
     // get indices
     let indices = state
@@ -876,6 +1436,8 @@ where
         .map(|ms| ms.index)
         .collect::<Vec<_>>();
 
+    let aod = Aod::submenu(state);
+
     // * update active item
     let Some(last_menu_state) = state.menu_states.last_mut() else {
         // no menus left
@@ -883,8 +1445,8 @@ where
 
         // keep state.open when the cursor is still inside the menu bar
         // this allows the overlay to keep drawing when the cursor is
-        // moving aroung the menu bar
-        if !menu.bar_bounds.contains(overlay_cursor) {
+        // moving aroung the menu bar or it has keyboard focus
+        if !menu.bar_bounds.contains(overlay_cursor) && state.focused_root.is_none() {
             state.open = false;
         }
         return Captured;
@@ -930,9 +1492,16 @@ where
                 active_menu,
             )
         }
+        ItemHeight::Flex(_) => last_menu_bounds
+            .child_positions
+            .partition_point(|&p| p <= height_diff)
+            .saturating_sub(1),
     };
 
     // set new index
+    if last_menu_state.index != Some(new_index) {
+        state.hover_start = now;
+    }
     last_menu_state.index = Some(new_index);
 
     // get new active item
@@ -940,43 +1509,24 @@ where
 
     // * add new menu if the new item is a menu
     if !item.children.is_empty() {
-        let item_position = Point::new(
-            0.0,
-            last_menu_bounds.child_positions[new_index] + last_menu_state.scroll_offset,
-        );
-        let item_size = get_item_size(
+        let open_time = state.hover_start + menu.open_delay;
+        if now < open_time {
+            schedule_hover_update(state, open_time, shell);
+            return Captured;
+        }
+
+        let submenu = last_menu_state.submenu(
+            new_index,
             item,
-            last_menu_bounds.children_bounds.width,
+            menu.item_width,
             menu.item_height,
+            menu.bounds_expand,
+            &aod,
+            viewport_size,
+            overlay_offset,
+            renderer,
         );
-
-        // overlay space item bounds
-        let item_bounds = Rectangle::new(item_position, item_size)
-            + (last_menu_bounds.children_bounds.position() - Point::ORIGIN);
-
-        let aod = Aod {
-            horizontal: true,
-            vertical: true,
-            horizontal_overlap: false,
-            vertical_overlap: true,
-            horizontal_direction: state.horizontal_direction,
-            vertical_direction: state.vertical_direction,
-        };
-
-        state.menu_states.push(MenuState {
-            index: None,
-            scroll_offset: 0.0,
-            menu_bounds: MenuBounds::new(
-                item,
-                menu.item_width,
-                menu.item_height,
-                viewport_size,
-                overlay_offset,
-                &aod,
-                menu.bounds_expand,
-                item_bounds,
-            ),
-        });
+        state.menu_states.push(submenu);
     }
 
     Captured
@@ -1059,70 +1609,80 @@ where
     Captured
 }
 
-fn get_item_size<Message, Renderer>(
-    menu_tree: &MenuTree<'_, Message, Renderer>,
-    width: f32,
-    item_height: ItemHeight,
-) -> Size
-where
-    Renderer: renderer::Renderer,
-{
-    let height = match item_height {
-        ItemHeight::Uniform(u) => f32::from(u),
-        ItemHeight::Static(s) => f32::from(menu_tree.height.unwrap_or(s)),
-    };
-
-    Size::new(width, height)
-}
-
-fn get_children_size<Message, Renderer>(
+/// Returns the width of the items of a menu, widened to fit their shortcut labels
+/// to the right of the items' contents
+fn get_children_width<Message, Renderer>(
     menu_tree: &MenuTree<'_, Message, Renderer>,
     item_width: ItemWidth,
-    item_height: ItemHeight,
-) -> Size
+    renderer: &Renderer,
+) -> f32
 where
-    Renderer: renderer::Renderer,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
 {
     let width = match item_width {
         ItemWidth::Uniform(u) => f32::from(u),
         ItemWidth::Static(s) => f32::from(menu_tree.width.unwrap_or(s)),
     };
 
-    let height = match item_height {
-        ItemHeight::Uniform(u) => f32::from(u) * (menu_tree.children.len() as f32),
-        ItemHeight::Static(s) => menu_tree
-            .children
-            .iter()
-            .fold(0.0, |h, mt| h + f32::from(mt.height.unwrap_or(s))),
-    };
-
-    Size::new(width, height)
+    let shortcut_width = menu_tree
+        .children
+        .iter()
+        .filter_map(|mt| mt.shortcut)
+        .map(|shortcut| {
+            renderer.measure_width(
+                &shortcut.to_string(),
+                renderer.default_size(),
+                renderer.default_font(),
+                text::Shaping::Basic,
+            ) + 2.0 * SHORTCUT_PADDING
+        })
+        .fold(0.0, f32::max);
+
+    width + shortcut_width
 }
 
-fn get_child_positions<Message, Renderer>(
+fn get_child_heights<Message, Renderer>(
     menu_tree: &MenuTree<'_, Message, Renderer>,
+    width: f32,
     item_height: ItemHeight,
+    renderer: &Renderer,
 ) -> Vec<f32>
 where
     Renderer: renderer::Renderer,
 {
-    match item_height {
-        ItemHeight::Uniform(u) => {
-            let children_count = menu_tree.children.len();
-            (0..children_count)
-                .map(|i| (i as f32) * f32::from(u))
-                .collect()
-        }
-        ItemHeight::Static(s) => {
-            let max_index = menu_tree.children.len() - 1;
-            std::iter::once(0.0)
-                .chain(menu_tree.children[0..max_index].iter().scan(0.0, |p, mt| {
-                    *p += f32::from(mt.height.unwrap_or(s));
-                    Some(*p)
-                }))
-                .collect::<Vec<_>>()
-        }
-    }
+    menu_tree
+        .children
+        .iter()
+        .map(|mt| match item_height {
+            ItemHeight::Uniform(u) => f32::from(u),
+            ItemHeight::Static(s) => f32::from(mt.height.unwrap_or(s)),
+            ItemHeight::Flex(f) => mt.height.map_or_else(
+                || {
+                    let limits = Limits::new(Size::ZERO, Size::new(width, f32::INFINITY));
+                    let height = mt.item.as_widget().layout(renderer, &limits).size().height;
+
+                    // items filling the available height have no natural height
+                    if height.is_finite() && height > 0.0 {
+                        height
+                    } else {
+                        f32::from(f)
+                    }
+                },
+                f32::from,
+            ),
+        })
+        .collect()
+}
+
+fn get_child_positions(child_heights: &[f32]) -> Vec<f32> {
+    child_heights
+        .iter()
+        .scan(0.0, |p, h| {
+            let position = *p;
+            *p += h;
+            Some(position)
+        })
+        .collect()
 }
 
 fn search_bound<Message, Renderer>(
@@ -1153,3 +1713,105 @@ fn search_bound<Message, Renderer>(
     }
     index
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Aod, MenuBarState, MenuBounds, MenuState, Orientation};
+    use crate::native::menu::{MenuItem, MenuTree};
+    use iced_widget::core::{Point, Rectangle, Size, Vector};
+
+    #[test]
+    fn safe_triangle_points_towards_the_submenu() {
+        // an item at the top of a menu with its submenu open on the right
+        let parent_bounds = Rectangle::new(Point::new(0.0, 0.0), Size::new(100.0, 30.0));
+        let children_bounds = Rectangle::new(Point::new(100.0, 0.0), Size::new(100.0, 120.0));
+        let menu_bounds = MenuBounds {
+            child_positions: Vec::new(),
+            child_heights: Vec::new(),
+            children_bounds,
+            parent_bounds,
+            check_bounds: children_bounds,
+        };
+        let previous = Point::new(50.0, 20.0);
+
+        // moving diagonally down and right across the item below
+        assert!(menu_bounds.in_safe_triangle(previous, Point::new(70.0, 40.0)));
+        // moving straight down to the item below
+        assert!(!menu_bounds.in_safe_triangle(previous, Point::new(50.0, 40.0)));
+        // moving away from the submenu
+        assert!(!menu_bounds.in_safe_triangle(previous, Point::new(30.0, 40.0)));
+        // not moving at all
+        assert!(!menu_bounds.in_safe_triangle(previous, previous));
+    }
+
+    #[test]
+    fn vertical_menu_bar_opens_menus_to_the_side() {
+        let state = MenuBarState::default();
+        let root_bounds = Rectangle::new(Point::new(0.0, 100.0), Size::new(50.0, 20.0));
+        let children_size = Size::new(150.0, 60.0);
+        let viewport_size = Size::new(800.0, 600.0);
+
+        assert_eq!(
+            Aod::root(&state, Orientation::Horizontal).point(
+                root_bounds,
+                children_size,
+                viewport_size
+            ),
+            Point::new(0.0, 120.0)
+        );
+        assert_eq!(
+            Aod::root(&state, Orientation::Vertical).point(
+                root_bounds,
+                children_size,
+                viewport_size
+            ),
+            Point::new(50.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn keyboard_focus_skips_separators_and_disabled_items() {
+        let menu: MenuTree<'_, (), crate::Renderer> = MenuTree::with_children(
+            MenuItem::submenu("File"),
+            vec![
+                MenuItem::separator(),
+                MenuItem::action("Open", ()),
+                MenuItem::action("Save", ()).enabled(false),
+                MenuItem::separator(),
+                MenuItem::action("Quit", ()),
+            ],
+        );
+
+        assert_eq!(menu.focusable_child(0, true), Some(1));
+        assert_eq!(menu.focusable_child(2, true), Some(4));
+        assert_eq!(menu.focusable_child(5, true), Some(1));
+        assert_eq!(menu.focusable_child(3, false), Some(1));
+        assert_eq!(menu.focusable_child(0, false), Some(4));
+    }
+
+    #[test]
+    fn scroll_into_view_keeps_the_item_visible() {
+        let children_bounds = Rectangle::new(Point::new(0.0, 0.0), Size::new(100.0, 300.0));
+        let mut menu_state = MenuState {
+            index: None,
+            scroll_offset: 0.0,
+            menu_bounds: MenuBounds {
+                child_positions: (0..10).map(|i| i as f32 * 30.0).collect(),
+                child_heights: vec![30.0; 10],
+                children_bounds,
+                parent_bounds: Rectangle::default(),
+                check_bounds: children_bounds,
+            },
+        };
+        let viewport_size = Size::new(800.0, 100.0);
+
+        menu_state.scroll_into_view(9, viewport_size, Vector::ZERO);
+        assert!((menu_state.scroll_offset + 200.0).abs() < f32::EPSILON);
+
+        menu_state.scroll_into_view(5, viewport_size, Vector::ZERO);
+        assert!((menu_state.scroll_offset + 150.0).abs() < f32::EPSILON);
+
+        menu_state.scroll_into_view(0, viewport_size, Vector::ZERO);
+        assert!(menu_state.scroll_offset.abs() < f32::EPSILON);
+    }
+}
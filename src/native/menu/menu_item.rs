@@ -0,0 +1,470 @@
+//! Standard items for menus
+//!
+//! A [`MenuItem`] draws a label with an optional icon, checkmark, radio dot or
+//! submenu arrow and produces its message when it is clicked or activated with
+//! the keyboard. Converting it into a [`MenuTree`] keeps its [`Shortcut`].
+
+use super::{menu_tree::MenuTree, Shortcut};
+use crate::graphics::icons::{Icon, ICON_FONT};
+use crate::style::menu_bar::StyleSheet;
+
+use iced_widget::core::{
+    alignment::{Horizontal, Vertical},
+    event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    renderer, text, touch,
+    widget::{tree, Tree},
+    Clipboard, Color, Element, Event, Font, Layout, Length, Rectangle, Shell, Size, Widget,
+};
+
+/// The width of the column in front of the label holding the icon, checkmark or radio dot
+const MARKER_WIDTH: f32 = 20.0;
+/// The size of the dot of a selected radio item
+const RADIO_DOT_SIZE: f32 = 6.0;
+
+/// The kind of a [`MenuItem`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// A plain item producing a message
+    Action,
+    /// An item showing a checkmark when it is checked
+    Checkbox(bool),
+    /// An item of a group of which one is selected
+    Radio(bool),
+    /// A line between groups of items
+    Separator,
+    /// An item opening a submenu
+    Submenu,
+}
+
+/// A standard menu item
+///
+/// # Example
+///
+/// ```ignore
+/// use iced_aw::menu::{MenuItem, MenuTree, Shortcut};
+/// use iced::keyboard::{KeyCode, Modifiers};
+///
+/// let file = MenuTree::with_children(
+///     MenuItem::submenu("File"),
+///     vec![
+///         MenuItem::action("Save", Message::Save)
+///             .shortcut(Shortcut::new(Modifiers::CTRL, KeyCode::S))
+///             .into(),
+///         MenuItem::separator().into(),
+///         MenuItem::checkbox("Autosave", app.autosave, Message::Autosave).into(),
+///         MenuItem::action("Print", Message::Print).enabled(false).into(),
+///     ],
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MenuItem<Message, Renderer = crate::Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    kind: Kind,
+    label: String,
+    icon: Option<Icon>,
+    on_activate: Option<Message>,
+    shortcut: Option<Shortcut>,
+    enabled: bool,
+    padding: f32,
+    text_size: Option<f32>,
+    font: Option<Font>,
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+impl<Message, Renderer> MenuItem<Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    fn new(kind: Kind, label: impl Into<String>, on_activate: Option<Message>) -> Self {
+        Self {
+            kind,
+            label: label.into(),
+            icon: None,
+            on_activate,
+            shortcut: None,
+            enabled: true,
+            padding: 8.0,
+            text_size: None,
+            font: None,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Creates an item producing the given message when it is activated
+    #[must_use]
+    pub fn action(label: impl Into<String>, message: Message) -> Self {
+        Self::new(Kind::Action, label, Some(message))
+    }
+
+    /// Creates an item showing a checkmark when it is checked
+    ///
+    /// Activating it produces the message of `on_toggle` with the new value
+    #[must_use]
+    pub fn checkbox(
+        label: impl Into<String>,
+        checked: bool,
+        on_toggle: impl FnOnce(bool) -> Message,
+    ) -> Self {
+        Self::new(Kind::Checkbox(checked), label, Some(on_toggle(!checked)))
+    }
+
+    /// Creates an item of a radio group, showing a dot when `value` is the `selected` one
+    ///
+    /// Activating it produces the message of `on_select` with its value
+    #[must_use]
+    pub fn radio<V>(
+        label: impl Into<String>,
+        value: V,
+        selected: Option<V>,
+        on_select: impl FnOnce(V) -> Message,
+    ) -> Self
+    where
+        V: Eq + Copy,
+    {
+        Self::new(
+            Kind::Radio(selected == Some(value)),
+            label,
+            Some(on_select(value)),
+        )
+    }
+
+    /// Creates a line separating groups of items
+    #[must_use]
+    pub fn separator() -> Self {
+        Self::new(Kind::Separator, "", None).enabled(false)
+    }
+
+    /// Creates an item showing an arrow, to be used as the item of a [`MenuTree`] with children
+    #[must_use]
+    pub fn submenu(label: impl Into<String>) -> Self {
+        Self::new(Kind::Submenu, label, None)
+    }
+
+    /// Sets the icon shown in front of the label
+    #[must_use]
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the keyboard [`Shortcut`] of the item
+    ///
+    /// The shortcut is kept when the item is converted into a [`MenuTree`]
+    #[must_use]
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Sets whether the item can be activated, disabled items are drawn dimmed
+    #[must_use]
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets the horizontal padding of the item
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the label
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the label
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the style of the item
+    #[must_use]
+    pub fn style(mut self, style: impl Into<<Renderer::Theme as StyleSheet>::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// The state of a [`MenuItem`]
+#[derive(Debug, Default)]
+struct State {
+    is_pressed: bool,
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for MenuItem<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Fill
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let label_width = renderer.measure_width(
+            &self.label,
+            text_size,
+            self.font.unwrap_or_else(|| renderer.default_font()),
+            text::Shaping::Advanced,
+        );
+
+        let intrinsic = Size::new(
+            2.0 * self.padding + 2.0 * MARKER_WIDTH + label_width,
+            text_size * 1.5,
+        );
+
+        Node::new(
+            limits
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .resolve(intrinsic),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !self.enabled || self.on_activate.is_none() {
+            state.is_pressed = false;
+            return event::Status::Ignored;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if cursor.is_over(layout.bounds()) =>
+            {
+                state.is_pressed = true;
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if state.is_pressed =>
+            {
+                state.is_pressed = false;
+
+                if cursor.is_over(layout.bounds()) {
+                    if let Some(message) = self.on_activate.clone() {
+                        shell.publish(message);
+                    }
+                    return event::Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerLost { .. }) => state.is_pressed = false,
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.enabled && self.on_activate.is_some() && cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let styling = theme.appearance(&self.style);
+
+        if self.kind == Kind::Separator {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + self.padding,
+                        y: bounds.center_y().floor(),
+                        width: (bounds.width - 2.0 * self.padding).max(0.0),
+                        height: 1.0,
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                styling.separator,
+            );
+            return;
+        }
+
+        if self.enabled && cursor.is_over(bounds) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: styling.border_radius.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                styling.path,
+            );
+        }
+
+        let color = if self.enabled {
+            styling.text_color
+        } else {
+            styling.disabled_text_color
+        };
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        let marker_bounds = Rectangle {
+            x: bounds.x + self.padding,
+            width: MARKER_WIDTH,
+            ..bounds
+        };
+
+        let marker = match self.kind {
+            Kind::Checkbox(true) => Some(Icon::Check),
+            Kind::Radio(true) => {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: marker_bounds.center_x() - RADIO_DOT_SIZE / 2.0,
+                            y: marker_bounds.center_y() - RADIO_DOT_SIZE / 2.0,
+                            width: RADIO_DOT_SIZE,
+                            height: RADIO_DOT_SIZE,
+                        },
+                        border_radius: (RADIO_DOT_SIZE / 2.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color,
+                );
+                None
+            }
+            _ => self.icon,
+        };
+
+        if let Some(marker) = marker {
+            renderer.fill_text(text::Text {
+                content: &char::from(marker).to_string(),
+                bounds: Rectangle {
+                    x: marker_bounds.center_x(),
+                    y: marker_bounds.center_y(),
+                    ..marker_bounds
+                },
+                size: text_size,
+                line_height: text::LineHeight::default(),
+                color,
+                font: ICON_FONT,
+                horizontal_alignment: Horizontal::Center,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        }
+
+        renderer.fill_text(text::Text {
+            content: &self.label,
+            bounds: Rectangle {
+                x: marker_bounds.x + MARKER_WIDTH,
+                y: bounds.center_y(),
+                width: (bounds.width - 2.0 * self.padding - 2.0 * MARKER_WIDTH).max(0.0),
+                ..bounds
+            },
+            size: text_size,
+            line_height: text::LineHeight::default(),
+            color,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            horizontal_alignment: Horizontal::Left,
+            vertical_alignment: Vertical::Center,
+            shaping: text::Shaping::Advanced,
+        });
+
+        if self.kind == Kind::Submenu {
+            renderer.fill_text(text::Text {
+                content: &char::from(Icon::CaretRightFill).to_string(),
+                bounds: Rectangle {
+                    x: bounds.x + bounds.width - self.padding,
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: text_size * 0.75,
+                line_height: text::LineHeight::default(),
+                color,
+                font: ICON_FONT,
+                horizontal_alignment: Horizontal::Right,
+                vertical_alignment: Vertical::Center,
+                shaping: text::Shaping::Basic,
+            });
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuItem<Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(item: MenuItem<Message, Renderer>) -> Self {
+        Self::new(item)
+    }
+}
+
+impl<'a, Message, Renderer> From<MenuItem<Message, Renderer>> for MenuTree<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + renderer::Renderer + text::Renderer<Font = Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(item: MenuItem<Message, Renderer>) -> Self {
+        let shortcut = item.shortcut;
+        let focusable = item.enabled;
+        let tree = Self::new(item).focusable(focusable);
+
+        match shortcut {
+            Some(shortcut) => tree.shortcut(shortcut),
+            None => tree,
+        }
+    }
+}
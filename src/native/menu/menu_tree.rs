@@ -1,6 +1,10 @@
 //! A tree structure for constructing a hierarchical menu
 
-use iced_widget::core::{renderer, Element};
+use super::Shortcut;
+use iced_widget::core::{
+    keyboard::{KeyCode, Modifiers},
+    renderer, Element,
+};
 /// Nested menu is essentially a tree of items, a menu is a collection of items
 /// a menu itself can also be an item of another menu.
 ///
@@ -23,6 +27,10 @@ pub struct MenuTree<'a, Message, Renderer = crate::Renderer> {
     pub(super) width: Option<u16>,
     /// The height of the menu tree
     pub(super) height: Option<u16>,
+    /// The keyboard shortcut activating the item
+    pub(super) shortcut: Option<Shortcut>,
+    /// Whether the keyboard focus can move to the item
+    pub(super) focusable: bool,
 }
 impl<'a, Message, Renderer> MenuTree<'a, Message, Renderer>
 where
@@ -36,6 +44,8 @@ where
             children: Vec::new(),
             width: None,
             height: None,
+            shortcut: None,
+            focusable: true,
         }
     }
 
@@ -50,6 +60,8 @@ where
             children: children.into_iter().map(Into::into).collect(),
             width: None,
             height: None,
+            shortcut: None,
+            focusable: true,
         }
     }
 
@@ -73,6 +85,65 @@ where
         self
     }
 
+    /// Sets the keyboard [`Shortcut`] of the menu tree.
+    ///
+    /// The shortcut is displayed right-aligned in the item and the
+    /// [`MenuBar`] activates the item when the shortcut is pressed,
+    /// even when the menus are closed.
+    ///
+    /// [`MenuBar`]: super::MenuBar
+    #[must_use]
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Sets whether the keyboard focus can move to the menu tree.
+    ///
+    /// Separators and disabled [`MenuItem`]s are not focusable.
+    ///
+    /// [`MenuItem`]: super::MenuItem
+    #[must_use]
+    pub fn focusable(mut self, focusable: bool) -> Self {
+        self.focusable = focusable;
+        self
+    }
+
+    /// Finds the child the keyboard focus moves to from the child at `start`,
+    /// stepping forwards or backwards and wrapping around the children
+    pub(super) fn focusable_child(&self, start: usize, forward: bool) -> Option<usize> {
+        let count = self.children.len();
+        let start = start.checked_rem(count)?;
+
+        (0..count)
+            .map(|step| {
+                if forward {
+                    (start + step) % count
+                } else {
+                    (start + count - step) % count
+                }
+            })
+            .find(|&i| self.children[i].focusable)
+    }
+
+    /// Finds the item whose [`Shortcut`] is triggered by the pressed keys
+    pub(super) fn find_shortcut(
+        &mut self,
+        key_code: KeyCode,
+        modifiers: Modifiers,
+    ) -> Option<&mut Self> {
+        if self
+            .shortcut
+            .is_some_and(|shortcut| shortcut.matches(key_code, modifiers))
+        {
+            return Some(self);
+        }
+
+        self.children
+            .iter_mut()
+            .find_map(|c| c.find_shortcut(key_code, modifiers))
+    }
+
     /* Keep `set_index()` and `flattern()` recurse in the same order */
 
     /// Set the index of each item
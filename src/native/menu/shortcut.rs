@@ -0,0 +1,144 @@
+//! A keyboard shortcut activating a menu item
+
+use iced_widget::core::keyboard::{KeyCode, Modifiers};
+
+/// A key combined with modifiers that activates a [`MenuTree`] item
+/// while its [`MenuBar`] is part of the window, even when the menus are closed.
+///
+/// The modifiers have to match exactly, so `Ctrl+S` is not triggered by `Ctrl+Shift+S`.
+///
+/// Only shortcuts holding Ctrl, Alt or the logo key, or pressing a function key,
+/// are triggered, other keys are left to the widget with the keyboard focus.
+///
+/// [`MenuTree`]: super::MenuTree
+/// [`MenuBar`]: super::MenuBar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The modifiers that have to be held
+    pub modifiers: Modifiers,
+    /// The key that has to be pressed
+    pub key_code: KeyCode,
+}
+impl Shortcut {
+    /// Creates a new [`Shortcut`] from the given modifiers and key
+    #[must_use]
+    pub const fn new(modifiers: Modifiers, key_code: KeyCode) -> Self {
+        Self {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns true if the pressed key and modifiers trigger the [`Shortcut`]
+    #[must_use]
+    pub fn matches(&self, key_code: KeyCode, modifiers: Modifiers) -> bool {
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+
+    /// Returns true if the pressed key and modifiers can trigger a [`Shortcut`]
+    /// without getting in the way of typing into a focused widget
+    pub(super) fn can_trigger(key_code: KeyCode, modifiers: Modifiers) -> bool {
+        use KeyCode::{
+            F1, F10, F11, F12, F13, F14, F15, F16, F17, F18, F19, F2, F20, F21, F22, F23, F24, F3,
+            F4, F5, F6, F7, F8, F9,
+        };
+
+        modifiers.control()
+            || modifiers.alt()
+            || modifiers.logo()
+            || matches!(
+                key_code,
+                F1 | F2
+                    | F3
+                    | F4
+                    | F5
+                    | F6
+                    | F7
+                    | F8
+                    | F9
+                    | F10
+                    | F11
+                    | F12
+                    | F13
+                    | F14
+                    | F15
+                    | F16
+                    | F17
+                    | F18
+                    | F19
+                    | F20
+                    | F21
+                    | F22
+                    | F23
+                    | F24
+            )
+    }
+}
+
+impl std::fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let logo = if cfg!(target_os = "macos") {
+            "Cmd"
+        } else {
+            "Super"
+        };
+
+        for (pressed, name) in [
+            (self.modifiers.control(), "Ctrl"),
+            (self.modifiers.alt(), "Alt"),
+            (self.modifiers.shift(), "Shift"),
+            (self.modifiers.logo(), logo),
+        ] {
+            if pressed {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        // `KeyCode::Key1` is displayed as `1`
+        let key = format!("{:?}", self.key_code);
+        match key.strip_prefix("Key") {
+            Some(digit) if digit.len() == 1 => write!(f, "{digit}"),
+            _ => write!(f, "{key}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Shortcut;
+    use iced_widget::core::keyboard::{KeyCode, Modifiers};
+
+    #[test]
+    fn display_lists_modifiers_before_key() {
+        assert_eq!(
+            Shortcut::new(Modifiers::CTRL | Modifiers::SHIFT, KeyCode::S).to_string(),
+            "Ctrl+Shift+S"
+        );
+        assert_eq!(
+            Shortcut::new(Modifiers::ALT, KeyCode::Key4).to_string(),
+            "Alt+4"
+        );
+        assert_eq!(
+            Shortcut::new(Modifiers::empty(), KeyCode::F5).to_string(),
+            "F5"
+        );
+    }
+
+    #[test]
+    fn modifiers_match_exactly() {
+        let shortcut = Shortcut::new(Modifiers::CTRL, KeyCode::S);
+
+        assert!(shortcut.matches(KeyCode::S, Modifiers::CTRL));
+        assert!(!shortcut.matches(KeyCode::S, Modifiers::CTRL | Modifiers::SHIFT));
+        assert!(!shortcut.matches(KeyCode::D, Modifiers::CTRL));
+    }
+
+    #[test]
+    fn typed_keys_do_not_trigger_shortcuts() {
+        assert!(Shortcut::can_trigger(KeyCode::S, Modifiers::CTRL));
+        assert!(Shortcut::can_trigger(KeyCode::F4, Modifiers::ALT));
+        assert!(Shortcut::can_trigger(KeyCode::F5, Modifiers::empty()));
+        assert!(!Shortcut::can_trigger(KeyCode::S, Modifiers::empty()));
+        assert!(!Shortcut::can_trigger(KeyCode::S, Modifiers::SHIFT));
+    }
+}
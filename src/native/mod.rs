@@ -18,6 +18,13 @@ pub mod number_input;
 pub type NumberInput<'a, T, Message, Renderer> =
     number_input::NumberInput<'a, T, Message, Renderer>;
 
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "range_slider")]
+/// A slider with two handles for selecting a range of values.
+pub type RangeSlider<'a, T, Message, Renderer> =
+    range_slider::RangeSlider<'a, T, Message, Renderer>;
+
 #[cfg(feature = "card")]
 pub mod card;
 #[cfg(feature = "card")]
@@ -49,6 +56,20 @@ pub type SelectionList<'a, T, Message, Renderer> =
 #[cfg(feature = "selection_list")]
 pub use selection_list::List;
 
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+/// A text input paired with a list of the options matching its text.
+pub type ComboBox<'a, T, Message, Renderer> = combo_box::ComboBox<'a, T, Message, Renderer>;
+
+#[cfg(feature = "tree_view")]
+pub mod tree_view;
+#[cfg(feature = "tree_view")]
+/// A widget displaying hierarchical data as a tree of expandable nodes.
+pub type TreeView<'a, Id, Message, Renderer> = tree_view::TreeView<'a, Id, Message, Renderer>;
+#[cfg(feature = "tree_view")]
+pub use tree_view::TreeNode;
+
 #[cfg(feature = "floating_element")]
 pub mod floating_element;
 #[cfg(feature = "floating_element")]
@@ -62,7 +83,7 @@ pub mod grid;
 /// A container that distributes its contents in a grid.
 pub type Grid<'a, Message, Renderer> = grid::Grid<'a, Message, Renderer>;
 #[cfg(feature = "grid")]
-pub use grid::Strategy;
+pub use grid::{GridItem, LazyGrid, Strategy};
 
 #[cfg(feature = "icon_text")]
 pub mod icon_text;
@@ -82,11 +103,17 @@ pub mod split;
 /// A split can divide the available space by half to display two different elements.
 pub type Split<'a, Message, Renderer> = split::Split<'a, Message, Renderer>;
 
+#[cfg(feature = "table")]
+pub mod table;
+#[cfg(feature = "table")]
+/// A table displaying rows of elements below a header row.
+pub type Table<'a, Message, Renderer> = table::Table<'a, Message, Renderer>;
+
 #[cfg(feature = "tab_bar")]
 pub mod tab_bar;
 #[cfg(feature = "tab_bar")]
 /// A tab bar to show tabs.
-pub type TabBar<Message, TabId, Renderer> = tab_bar::TabBar<Message, TabId, Renderer>;
+pub type TabBar<'a, Message, TabId, Renderer> = tab_bar::TabBar<'a, Message, TabId, Renderer>;
 
 #[cfg(feature = "tab_bar")]
 pub use tab_bar::TabLabel;
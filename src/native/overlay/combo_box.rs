@@ -0,0 +1,146 @@
+//! The dropdown list of a [`ComboBox`](crate::native::combo_box::ComboBox).
+//!
+//! *This API requires the following crate features to be activated: `combo_box`*
+use crate::native::combo_box::{self, State};
+
+use iced_widget::core::{
+    self, event,
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer,
+    widget::Tree,
+    Clipboard, Event, Layout, Point, Rectangle, Shell, Size, Widget,
+};
+
+/// The dropdown list of a [`ComboBox`](crate::native::combo_box::ComboBox).
+#[allow(missing_debug_implementations)]
+pub struct ComboBoxOverlay<'a, 'b, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// The list of the options matching the query of the
+    /// [`ComboBox`](crate::native::combo_box::ComboBox).
+    list: &'b mut (dyn Widget<Message, Renderer> + 'a),
+    /// The tree of the list.
+    tree: &'b mut Tree,
+    /// The state shared with the [`ComboBox`](crate::native::combo_box::ComboBox).
+    state: &'b mut State,
+    /// The bounds of the text input the list drops down from.
+    input_bounds: Rectangle,
+}
+
+impl<'a, 'b, Message, Renderer> ComboBoxOverlay<'a, 'b, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+{
+    /// Creates a new [`ComboBoxOverlay`](ComboBoxOverlay) showing the `list`
+    /// below the text input with the given bounds.
+    pub(crate) fn new(
+        list: &'b mut (dyn Widget<Message, Renderer> + 'a),
+        tree: &'b mut Tree,
+        state: &'b mut State,
+        input_bounds: Rectangle,
+    ) -> Self {
+        ComboBoxOverlay {
+            list,
+            tree,
+            state,
+            input_bounds,
+        }
+    }
+
+    /// Turn this [`ComboBoxOverlay`] into an overlay
+    /// [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'b, Message, Renderer> {
+        overlay::Element::new(self.input_bounds.position(), Box::new(self))
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for ComboBoxOverlay<'a, '_, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let limits = Limits::new(
+            Size::ZERO,
+            Size::new(self.input_bounds.width, bounds.height),
+        );
+        let mut node = self.list.layout(renderer, &limits);
+        let height = node.size().height;
+        let below = position.y + self.input_bounds.height;
+
+        // Drop up instead if the list does not fit below the text input.
+        let y = if below + height > bounds.height && position.y - height >= 0.0 {
+            position.y - height
+        } else {
+            below
+        };
+
+        node.move_to(Point::new(position.x, y));
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        let (status, selected) = combo_box::with_selection(self.tree, |tree| {
+            self.list.on_event(
+                tree,
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                &layout.bounds(),
+            )
+        });
+
+        // Selecting an option closes the dropdown.
+        if selected {
+            self.state.is_open = false;
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.list
+            .mouse_interaction(self.tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        self.list.draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &layout.bounds(),
+        );
+    }
+}
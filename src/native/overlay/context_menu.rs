@@ -64,6 +64,27 @@ where
     }
 }
 
+/// Places content of the given size at `position` inside of the viewport.
+///
+/// The content flips to the other side of `position` on an axis where it
+/// would overflow the viewport and is clamped to the viewport if it doesn't fit either way.
+fn place(position: Point, size: Size, viewport: Size) -> Point {
+    let axis = |position: f32, size: f32, max: f32| {
+        if position + size <= max {
+            position
+        } else if position - size >= 0.0 {
+            position - size
+        } else {
+            (max - size).max(0.0)
+        }
+    };
+
+    Point::new(
+        axis(position.x, size.width, viewport.width),
+        axis(position.y, size.height, viewport.height),
+    )
+}
+
 impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
     for ContextMenuOverlay<'a, Message, Renderer>
 where
@@ -76,7 +97,7 @@ where
         let max_size = limits.max();
 
         let mut content = self.content.as_widget().layout(renderer, &limits);
-        content.move_to(position);
+        content.move_to(place(position, content.size(), max_size));
 
         Node::with_children(max_size, vec![content])
     }
@@ -204,3 +225,31 @@ where
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::place;
+    use iced_widget::core::{Point, Size};
+
+    #[test]
+    fn content_stays_inside_of_the_viewport() {
+        let viewport = Size::new(800.0, 600.0);
+        let size = Size::new(200.0, 100.0);
+
+        // enough space at the bottom right
+        assert_eq!(
+            place(Point::new(10.0, 20.0), size, viewport),
+            Point::new(10.0, 20.0)
+        );
+        // flipped to the top left
+        assert_eq!(
+            place(Point::new(700.0, 550.0), size, viewport),
+            Point::new(500.0, 450.0)
+        );
+        // clamped when it doesn't fit on either side
+        assert_eq!(
+            place(Point::new(100.0, 300.0), Size::new(200.0, 500.0), viewport),
+            Point::new(100.0, 100.0)
+        );
+    }
+}
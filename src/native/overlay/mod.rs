@@ -25,7 +25,17 @@ pub mod time_picker;
 #[cfg(feature = "time_picker")]
 pub use time_picker::{State, TimePickerOverlay};
 
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+pub use combo_box::ComboBoxOverlay;
+
 #[cfg(feature = "context_menu")]
 pub mod context_menu;
 #[cfg(feature = "context_menu")]
 pub use context_menu::ContextMenuOverlay;
+
+#[cfg(feature = "tab_bar")]
+pub mod tab_bar;
+#[cfg(feature = "tab_bar")]
+pub use tab_bar::TabBarOverlay;
@@ -0,0 +1,144 @@
+//! The list of the hidden tabs of a [`TabBar`](crate::native::tab_bar::TabBar).
+//!
+//! *This API requires the following crate features to be activated: `tab_bar`*
+use crate::native::tab_bar::TabBar;
+use crate::style::tab_bar::StyleSheet;
+
+use iced_widget::core::{
+    self, event,
+    layout::Node,
+    mouse::{self, Cursor},
+    overlay, renderer,
+    widget::Tree,
+    Clipboard, Event, Layout, Point, Rectangle, Shell, Size,
+};
+
+/// The list of the hidden tabs of a [`TabBar`](crate::native::tab_bar::TabBar).
+#[allow(missing_debug_implementations)]
+pub struct TabBarOverlay<'a, 'b, Message, TabId, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+    TabId: Eq + Clone,
+{
+    /// The [`TabBar`](crate::native::tab_bar::TabBar) the list belongs to.
+    tab_bar: &'b TabBar<'a, Message, TabId, Renderer>,
+    /// The tree shared with the [`TabBar`](crate::native::tab_bar::TabBar).
+    tree: &'b mut Tree,
+    /// The bounds of the button opening the list.
+    button: Rectangle,
+    /// The indices of the hidden tabs.
+    hidden: Vec<usize>,
+}
+
+impl<'a, 'b, Message, TabId, Renderer> TabBarOverlay<'a, 'b, Message, TabId, Renderer>
+where
+    Renderer: 'b + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+    TabId: Eq + Clone,
+{
+    /// Creates a new [`TabBarOverlay`](TabBarOverlay) listing the hidden tabs
+    /// below the button with the given bounds.
+    pub(crate) fn new(
+        tab_bar: &'b TabBar<'a, Message, TabId, Renderer>,
+        tree: &'b mut Tree,
+        button: Rectangle,
+        hidden: Vec<usize>,
+    ) -> Self {
+        TabBarOverlay {
+            tab_bar,
+            tree,
+            button,
+            hidden,
+        }
+    }
+
+    /// Turn this [`TabBarOverlay`] into an overlay
+    /// [`Element`](overlay::Element).
+    #[must_use]
+    pub fn overlay(self) -> overlay::Element<'b, Message, Renderer> {
+        overlay::Element::new(self.button.position(), Box::new(self))
+    }
+}
+
+impl<'b, Message, TabId, Renderer> overlay::Overlay<Message, Renderer>
+    for TabBarOverlay<'_, 'b, Message, TabId, Renderer>
+where
+    Renderer: 'b + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+    TabId: Eq + Clone,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+        let size = self.tab_bar.dropdown_size(renderer, &self.hidden);
+        let below = position.y + self.button.height;
+        let space_below = (bounds.height - below).max(0.0);
+        let space_above = position.y.max(0.0);
+
+        // The list ends at the right edge of the button and drops up if it
+        // does not fit below it. It is cut to the larger space and scrolled
+        // if it fits neither below nor above the button.
+        let x = (position.x + self.button.width - size.width).max(0.0);
+        let (y, height) = if size.height <= space_below || space_below >= space_above {
+            (below, size.height.min(space_below))
+        } else {
+            let height = size.height.min(space_above);
+            (position.y - height, height)
+        };
+
+        let mut node = Node::new(Size::new(size.width, height));
+        node.move_to(Point::new(x, y));
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<Message>,
+    ) -> event::Status {
+        self.tab_bar.dropdown_on_event(
+            self.tree,
+            &event,
+            layout.bounds(),
+            self.button,
+            &self.hidden,
+            cursor,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+    ) {
+        self.tab_bar.draw_dropdown(
+            self.tree,
+            renderer,
+            theme,
+            layout.bounds(),
+            &self.hidden,
+            cursor,
+        );
+    }
+}
@@ -0,0 +1,659 @@
+//! Use a range slider to select a range of values between two bounds.
+//!
+//! *This API requires the following crate features to be activated: `range_slider`*
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event, keyboard,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        renderer, text, touch,
+        widget::{
+            tree::{State as TreeState, Tag},
+            Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+
+use num_traits::{FromPrimitive, Num, NumAssignOps, ToPrimitive};
+use std::{fmt::Display, ops::RangeInclusive};
+
+pub use crate::style::range_slider::{Appearance, RangeSliderStyles, StyleSheet};
+
+/// The default height of the rail area of a [`RangeSlider`](RangeSlider).
+const DEFAULT_HEIGHT: f32 = 22.0;
+/// The spacing between the rail area and the value labels.
+const LABEL_SPACING: f32 = 4.0;
+/// The amount of steps a handle is moved by `PageUp` and `PageDown`.
+const PAGE_STEPS: u8 = 10;
+/// The minimum distance in pixels between two tick marks.
+const MIN_TICK_DISTANCE: f32 = 2.0;
+
+/// A horizontal rail with two handles selecting a range of values.
+///
+/// The value type is generic over the same numeric traits as the
+/// [`NumberInput`](crate::native::number_input::NumberInput), additionally requiring
+/// [`ToPrimitive`](ToPrimitive) and [`FromPrimitive`](FromPrimitive) to convert
+/// between values and positions on the rail.
+///
+/// # Example
+/// ```ignore
+/// # use iced_aw::RangeSlider;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     RangeChanged((u32, u32)),
+/// }
+///
+/// let slider = RangeSlider::new(0..=100, (20, 80), Message::RangeChanged)
+///     .step(5)
+///     .tick_marks(10)
+///     .show_labels(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The inclusive bounds of the [`RangeSlider`](RangeSlider).
+    range: RangeInclusive<T>,
+    /// The currently selected lower and upper value of the [`RangeSlider`](RangeSlider).
+    values: (T, T),
+    /// The step the handles of the [`RangeSlider`](RangeSlider) snap to.
+    step: T,
+    /// The message that is send when one of the values changes.
+    on_change: Box<dyn Fn((T, T)) -> Message + 'a>,
+    /// The message that is send when a handle is released.
+    on_release: Option<Message>,
+    /// The optional interval of the tick marks.
+    tick_interval: Option<T>,
+    /// Whether the values are shown below the handles.
+    show_labels: bool,
+    /// The width of the [`RangeSlider`](RangeSlider).
+    width: Length,
+    /// The height of the rail area of the [`RangeSlider`](RangeSlider).
+    height: f32,
+    /// The text size of the value labels.
+    text_size: Option<f32>,
+    /// The font of the value labels.
+    font: Renderer::Font,
+    /// The style of the [`RangeSlider`](RangeSlider).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, T, Message, Renderer> RangeSlider<'a, T, Message, Renderer>
+where
+    T: Num + NumAssignOps + PartialOrd + Display + Copy + ToPrimitive + FromPrimitive,
+    Message: Clone,
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`RangeSlider`](RangeSlider).
+    ///
+    /// It expects:
+    ///     - The inclusive bounds of the selectable values.
+    ///     - The currently selected lower and upper value.
+    ///     - The function producing the message when one of the values changes.
+    pub fn new<F>(range: RangeInclusive<T>, values: (T, T), on_change: F) -> Self
+    where
+        F: 'a + Fn((T, T)) -> Message,
+    {
+        let (low, high) = if values.0 <= values.1 {
+            values
+        } else {
+            (values.1, values.0)
+        };
+
+        Self {
+            values: (
+                clamp(low, *range.start(), *range.end()),
+                clamp(high, *range.start(), *range.end()),
+            ),
+            range,
+            step: T::one(),
+            on_change: Box::new(on_change),
+            on_release: None,
+            tick_interval: None,
+            show_labels: false,
+            width: Length::Fill,
+            height: DEFAULT_HEIGHT,
+            text_size: None,
+            font: Renderer::Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the step of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn step(mut self, step: T) -> Self {
+        if step > T::zero() {
+            self.step = step;
+        }
+        self
+    }
+
+    /// Sets the message that is send when a handle of the [`RangeSlider`](RangeSlider)
+    /// is released.
+    #[must_use]
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Draws tick marks on the rail of the [`RangeSlider`](RangeSlider) every `interval`.
+    #[must_use]
+    pub fn tick_marks(mut self, interval: T) -> Self {
+        self.tick_interval = (interval > T::zero()).then_some(interval);
+        self
+    }
+
+    /// Sets whether the values are shown below the handles of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn show_labels(mut self, show_labels: bool) -> Self {
+        self.show_labels = show_labels;
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the rail area of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the value labels of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the value labels of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the bounds of the rail area inside the bounds of the widget.
+    fn rail_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let inset = self.height / 2.0;
+        Rectangle {
+            x: bounds.x + inset,
+            y: bounds.y,
+            width: (bounds.width - self.height).max(0.0),
+            height: self.height,
+        }
+    }
+
+    /// Returns the horizontal position of the given value on the rail.
+    fn position_of(&self, value: T, rail: Rectangle) -> f32 {
+        let start = to_f64(*self.range.start());
+        let end = to_f64(*self.range.end());
+
+        if end <= start {
+            return rail.x;
+        }
+
+        rail.x + rail.width * ((to_f64(value) - start) / (end - start)) as f32
+    }
+
+    /// Returns the value snapped to the step at the given horizontal position.
+    fn value_at(&self, x: f32, rail: Rectangle) -> Option<T> {
+        if x <= rail.x || rail.width <= 0.0 {
+            return Some(*self.range.start());
+        }
+        if x >= rail.x + rail.width {
+            return Some(*self.range.end());
+        }
+
+        let start = to_f64(*self.range.start());
+        let end = to_f64(*self.range.end());
+        let step = to_f64(self.step);
+        let percent = f64::from((x - rail.x) / rail.width);
+        let steps = (percent * (end - start) / step).round();
+
+        T::from_f64(steps.mul_add(step, start))
+            .map(|value| clamp(value, *self.range.start(), *self.range.end()))
+    }
+
+    /// Moves the given [`Thumb`](Thumb) to the value, keeping the handles ordered,
+    /// and publishes the change.
+    fn set_value(&mut self, thumb: Thumb, value: T, shell: &mut Shell<'_, Message>) {
+        let (low, high) = self.values;
+        let values = match thumb {
+            Thumb::Lower => (clamp(value, *self.range.start(), high), high),
+            Thumb::Upper => (low, clamp(value, low, *self.range.end())),
+        };
+
+        if values.0 != self.values.0 || values.1 != self.values.1 {
+            self.values = values;
+            shell.publish((self.on_change)(values));
+        }
+    }
+
+    /// Returns the current value of the given [`Thumb`](Thumb).
+    fn value_of(&self, thumb: Thumb) -> T {
+        match thumb {
+            Thumb::Lower => self.values.0,
+            Thumb::Upper => self.values.1,
+        }
+    }
+
+    /// Returns the [`Thumb`](Thumb) that should be grabbed at the given position.
+    fn closest_thumb(&self, x: f32, rail: Rectangle) -> Thumb {
+        let lower = self.position_of(self.values.0, rail);
+        let upper = self.position_of(self.values.1, rail);
+
+        if (upper - lower).abs() < f32::EPSILON {
+            if x < lower {
+                Thumb::Lower
+            } else {
+                Thumb::Upper
+            }
+        } else if (x - lower).abs() <= (x - upper).abs() {
+            Thumb::Lower
+        } else {
+            Thumb::Upper
+        }
+    }
+
+    /// Returns the height of the value labels including the spacing.
+    fn label_height(&self, renderer: &Renderer) -> f32 {
+        if self.show_labels {
+            let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+            LineHeight::default().to_absolute(size.into()).0 + LABEL_SPACING
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<T, Message, Renderer> Widget<Message, Renderer> for RangeSlider<'_, T, Message, Renderer>
+where
+    T: Num + NumAssignOps + PartialOrd + Display + Copy + ToPrimitive + FromPrimitive,
+    Message: Clone,
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::new())
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits
+            .width(self.width)
+            .height(Length::Fixed(self.height + self.label_height(renderer)));
+
+        Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) -> event::Status {
+        let state: &mut State = state.state.downcast_mut();
+        let bounds = layout.bounds();
+        let rail = self.rail_bounds(bounds);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let thumb = self.closest_thumb(position.x, rail);
+                    state.dragging = Some(thumb);
+                    state.focused = Some(thumb);
+
+                    if let Some(value) = self.value_at(position.x, rail) {
+                        self.set_value(thumb, value, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                state.focused = None;
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if state.dragging.is_some() =>
+            {
+                state.dragging = None;
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+
+                return event::Status::Captured;
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(thumb) = state.dragging {
+                    if let Some(value) = self.value_at(position.x, rail) {
+                        self.set_value(thumb, value, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => {
+                let Some(thumb) = state.focused else {
+                    return event::Status::Ignored;
+                };
+                let value = self.value_of(thumb);
+                let mut page = T::zero();
+                for _ in 0..PAGE_STEPS {
+                    page += self.step;
+                }
+
+                let new_value = match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                        Some(saturating_sub(value, self.step, *self.range.start()))
+                    }
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                        Some(saturating_add(value, self.step, *self.range.end()))
+                    }
+                    keyboard::KeyCode::PageDown => {
+                        Some(saturating_sub(value, page, *self.range.start()))
+                    }
+                    keyboard::KeyCode::PageUp => {
+                        Some(saturating_add(value, page, *self.range.end()))
+                    }
+                    keyboard::KeyCode::Home => Some(*self.range.start()),
+                    keyboard::KeyCode::End => Some(*self.range.end()),
+                    keyboard::KeyCode::Tab => {
+                        match (thumb, modifiers.shift()) {
+                            (Thumb::Lower, false) => state.focused = Some(Thumb::Upper),
+                            (Thumb::Upper, true) => state.focused = Some(Thumb::Lower),
+                            _ => {
+                                state.focused = None;
+                                return event::Status::Ignored;
+                            }
+                        }
+                        return event::Status::Captured;
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.focused = None;
+                        return event::Status::Captured;
+                    }
+                    _ => None,
+                };
+
+                if let Some(new_value) = new_value {
+                    self.set_value(thumb, new_value, shell);
+                    return event::Status::Captured;
+                }
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state: &State = state.state.downcast_ref();
+
+        if state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state: &State = state.state.downcast_ref();
+        let bounds = layout.bounds();
+        let rail = self.rail_bounds(bounds);
+        let rail_y = rail.center_y();
+
+        let style = if state.dragging.is_some() {
+            theme.dragged(self.style)
+        } else if cursor.is_over(bounds) {
+            theme.hovered(self.style)
+        } else {
+            theme.active(self.style)
+        };
+
+        // Tick marks
+        if let Some(interval) = self.tick_interval {
+            let start = to_f64(*self.range.start());
+            let end = to_f64(*self.range.end());
+            let interval = to_f64(interval);
+
+            if end > start && rail.width * ((interval / (end - start)) as f32) >= MIN_TICK_DISTANCE
+            {
+                let ticks = ((end - start) / interval).floor() as usize;
+                for tick in 0..=ticks {
+                    let x = rail.x + rail.width * ((tick as f64 * interval) / (end - start)) as f32;
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: x - 0.5,
+                                y: rail_y - style.handle_radius / 2.0,
+                                width: 1.0,
+                                height: style.handle_radius,
+                            },
+                            border_radius: 0.0.into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        style.tick_color,
+                    );
+                }
+            }
+        }
+
+        // Rail
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: rail.x,
+                    y: rail_y - style.rail_width / 2.0,
+                    width: rail.width,
+                    height: style.rail_width,
+                },
+                border_radius: style.rail_border_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            style.rail_background,
+        );
+
+        let lower = self.position_of(self.values.0, rail);
+        let upper = self.position_of(self.values.1, rail);
+
+        // Selected range
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    x: lower,
+                    y: rail_y - style.rail_width / 2.0,
+                    width: upper - lower,
+                    height: style.rail_width,
+                },
+                border_radius: style.rail_border_radius.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            style.range_background,
+        );
+
+        // Handles and labels
+        for (thumb, x) in [(Thumb::Lower, lower), (Thumb::Upper, upper)] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x - style.handle_radius,
+                        y: rail_y - style.handle_radius,
+                        width: style.handle_radius * 2.0,
+                        height: style.handle_radius * 2.0,
+                    },
+                    border_radius: style.handle_radius.into(),
+                    border_width: style.handle_border_width,
+                    border_color: if state.focused == Some(thumb) {
+                        style.focused_handle_border_color
+                    } else {
+                        style.handle_border_color
+                    },
+                },
+                style.handle_background,
+            );
+
+            if self.show_labels {
+                let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+                renderer.fill_text(text::Text {
+                    content: &self.value_of(thumb).to_string(),
+                    bounds: Rectangle {
+                        x,
+                        y: rail.y + rail.height + LABEL_SPACING,
+                        width: bounds.width,
+                        height: bounds.height - rail.height - LABEL_SPACING,
+                    },
+                    size,
+                    line_height: LineHeight::default(),
+                    color: style.label_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Top,
+                    shaping: text::Shaping::Basic,
+                });
+            }
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<RangeSlider<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Num + NumAssignOps + PartialOrd + Display + Copy + ToPrimitive + FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(range_slider: RangeSlider<'a, T, Message, Renderer>) -> Self {
+        Element::new(range_slider)
+    }
+}
+
+/// One of the two handles of a [`RangeSlider`](RangeSlider).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Thumb {
+    /// The handle of the lower value.
+    Lower,
+    /// The handle of the upper value.
+    Upper,
+}
+
+/// The state of a [`RangeSlider`](RangeSlider).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct State {
+    /// The handle that is currently dragged by the user.
+    dragging: Option<Thumb>,
+    /// The handle that currently receives the keyboard input.
+    focused: Option<Thumb>,
+}
+
+impl State {
+    /// Creates a new [`State`](State) for a [`RangeSlider`](RangeSlider).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            dragging: None,
+            focused: None,
+        }
+    }
+}
+
+/// Converts the value to a `f64` for positioning.
+fn to_f64<T: ToPrimitive + Copy>(value: T) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+/// Clamps the value between `min` and `max`.
+fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Subtracts `step` from `value` without going below `min`.
+fn saturating_sub<T: Num + PartialOrd + Copy>(value: T, step: T, min: T) -> T {
+    if value - min < step {
+        min
+    } else {
+        value - step
+    }
+}
+
+/// Adds `step` to `value` without going above `max`.
+fn saturating_add<T: Num + PartialOrd + Copy>(value: T, step: T, max: T) -> T {
+    if max - value < step {
+        max
+    } else {
+        value + step
+    }
+}
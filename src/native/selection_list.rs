@@ -9,20 +9,37 @@ use iced_widget::{
         layout::{Limits, Node},
         mouse::{self, Cursor},
         renderer,
-        widget::Tree,
-        Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+        widget::{
+            tree::{State, Tag},
+            Tree,
+        },
+        Clipboard, Element, Event, Layout, Length, Padding, Point, Rectangle, Shell, Size, Widget,
     },
     runtime::Font,
-    scrollable, text,
+    scrollable::{self, AbsoluteOffset, Direction},
+    text,
     text::LineHeight,
-    Container, Scrollable,
 };
 
-pub use list::List;
+#[cfg(feature = "combo_box")]
+use iced_widget::core::keyboard::{self, KeyCode};
+pub use list::{Drag, ItemState, List, ListState};
 use std::marker::PhantomData;
 use std::{borrow::Cow, hash::Hash};
 
 /// A widget for selecting a single value from a dynamic scrollable list of options.
+///
+/// Only the options that are visible inside of the scrollable viewport are
+/// drawn and hit-tested, and every option has the same height. Use
+/// [`item_height`](SelectionList::item_height) to set that height explicitly and
+/// a width other than [`Length::Shrink`] to keep very large lists from being
+/// measured on every layout. Options of different or estimated heights are
+/// not supported.
+///
+/// Clicking the list focuses it. While focused, the arrow keys, Home, End,
+/// Page Up and Page Down move the highlighted option and scroll it into view,
+/// Enter selects it and typing jumps to the first option starting with the
+/// typed characters.
 #[allow(missing_debug_implementations)]
 #[allow(clippy::type_repetition_in_bounds)]
 pub struct SelectionList<'a, T, Message, Renderer = crate::Renderer>
@@ -32,8 +49,8 @@ where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: StyleSheet + container::StyleSheet,
 {
-    /// Container for Rendering List.
-    container: Container<'a, Message, Renderer>,
+    /// The list rendering the options inside of the scrollable.
+    list: List<'a, T, Message, Renderer>,
     /// List of Elements to Render.
     options: Cow<'a, [T]>,
     /// Label Font
@@ -65,31 +82,17 @@ where
     /// to set those.
     pub fn new(
         options: impl Into<Cow<'a, [T]>>,
-        on_selected: impl Fn((usize, T)) -> Message + 'static,
+        on_selected: impl Fn((usize, T)) -> Message + 'a,
     ) -> Self {
-        let options = options.into();
-        let container = Container::new(Scrollable::new(List {
-            options: options.clone(),
-            font: Font::default(),
-            text_size: 12.0,
-            padding: 5.0,
-            style: <Renderer::Theme as StyleSheet>::Style::default(),
-            on_selected: Box::new(on_selected),
-            selected: None,
-            phantomdata: PhantomData,
-        }))
-        .padding(1);
-
-        Self {
+        Self::new_with(
             options,
-            font: Font::default(),
-            style: <Renderer::Theme as StyleSheet>::Style::default(),
-            container,
-            width: Length::Fill,
-            height: Length::Fill,
-            padding: 5.0,
-            text_size: 12.0,
-        }
+            on_selected,
+            12.0,
+            5.0,
+            <Renderer::Theme as StyleSheet>::Style::default(),
+            None,
+            Font::default(),
+        )
     }
 
     /// Creates a new [`SelectionList`] with the given list of `options`,
@@ -97,7 +100,7 @@ where
     /// selected, the `style`, `text_size`, `padding` and `font`.
     pub fn new_with(
         options: impl Into<Cow<'a, [T]>>,
-        on_selected: impl Fn((usize, T)) -> Message + 'static,
+        on_selected: impl Fn((usize, T)) -> Message + 'a,
         text_size: f32,
         padding: f32,
         style: <Renderer::Theme as StyleSheet>::Style,
@@ -105,31 +108,56 @@ where
         font: Font,
     ) -> Self {
         let options = options.into();
-        let container = Container::new(Scrollable::new(List {
-            options: options.clone(),
-            font,
-            text_size,
-            padding,
-            style,
-            selected,
-            on_selected: Box::new(on_selected),
-            phantomdata: PhantomData,
-        }))
-        .padding(1);
 
         Self {
+            list: List {
+                options: options.clone(),
+                font,
+                text_size,
+                padding,
+                item_height: None,
+                style,
+                selected,
+                on_selected: Box::new(on_selected),
+                on_selection_change: None,
+                selection: None,
+                on_reorder: None,
+                view: None,
+                phantomdata: PhantomData,
+            },
             options,
             font,
-            style,
-            container,
             width: Length::Fill,
             height: Length::Fill,
             padding,
             text_size,
+            style,
         }
     }
 
+    /// Creates a new [`SelectionList`] with the given list of `options`, the
+    /// `message` to produce when an option is selected and a `view` building the
+    /// content of every option from the option and its [`ItemState`].
+    ///
+    /// Selection, hovering and keyboard navigation are handled by the
+    /// [`SelectionList`] and text inside of the content takes the color of the
+    /// style for the state of the option. The content is only drawn and does not
+    /// receive events itself, and its state is kept while the option is visible.
+    /// Every option has the same height, which usually has to be set with
+    /// [`item_height`](SelectionList::item_height).
+    pub fn with_view(
+        options: impl Into<Cow<'a, [T]>>,
+        on_selected: impl Fn((usize, T)) -> Message + 'a,
+        view: impl Fn(&T, ItemState) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        let mut selection_list = Self::new(options, on_selected);
+        selection_list.list.view = Some(Box::new(view));
+        selection_list
+    }
+
     /// Sets the width of the [`SelectionList`](SelectionList).
+    ///
+    /// A width of [`Length::Shrink`] measures the label of every option.
     #[must_use]
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -143,27 +171,92 @@ where
         self
     }
 
+    /// Sets the fixed height of every option of the [`SelectionList`](SelectionList).
+    ///
+    /// Defaults to the text size plus twice the padding.
+    #[must_use]
+    pub fn item_height(mut self, height: f32) -> Self {
+        self.list.item_height = Some(height);
+        self
+    }
+
+    /// Allows selecting multiple options of the [`SelectionList`](SelectionList).
+    ///
+    /// Clicking an option with Ctrl toggles it, clicking with Shift selects the
+    /// range from the last clicked option and Ctrl+A selects all options. The
+    /// given function is called with every selected option whenever the
+    /// selection changes. `on_selected` is only called for clicks without modifiers.
+    #[must_use]
+    pub fn multi_select(
+        mut self,
+        on_selection_change: impl Fn(Vec<(usize, T)>) -> Message + 'a,
+    ) -> Self {
+        self.list.on_selection_change = Some(Box::new(on_selection_change));
+        self
+    }
+
+    /// Allows reordering the options of the [`SelectionList`](SelectionList)
+    /// by dragging them.
+    ///
+    /// A line shows where the dragged option will be dropped and the list
+    /// scrolls while the option is dragged near its top or bottom edge. The
+    /// given function is called with the index the option was dragged from and
+    /// the index it should be moved to.
+    #[must_use]
+    pub fn on_reorder(mut self, on_reorder: impl Fn(usize, usize) -> Message + 'a) -> Self {
+        self.list.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Sets the selected options of a multi select [`SelectionList`](SelectionList) manually.
+    #[must_use]
+    pub fn selected_indices(mut self, indices: impl Into<Vec<usize>>) -> Self {
+        self.list.selection = Some(indices.into());
+        self
+    }
+
     /// Sets the style of the [`SelectionList`](SelectionList).
     #[must_use]
     pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
         self.style = style;
+        self.list.style = style;
         self
     }
+
+    /// The options of the [`SelectionList`](SelectionList).
+    #[cfg(feature = "combo_box")]
+    pub(crate) fn options(&self) -> &[T] {
+        &self.options
+    }
+
+    /// The list of the [`SelectionList`](SelectionList) to configure.
+    #[cfg(feature = "combo_box")]
+    pub(crate) fn list_mut(&mut self) -> &mut List<'a, T, Message, Renderer> {
+        &mut self.list
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer> for SelectionList<'a, T, Message, Renderer>
 where
     T: 'a + Clone + ToString + Eq + Hash,
-    Message: 'static,
+    Message: 'a,
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font> + 'a,
-    Renderer::Theme: StyleSheet + container::StyleSheet,
+    Renderer::Theme: StyleSheet + container::StyleSheet + scrollable::StyleSheet,
 {
+    fn tag(&self) -> Tag {
+        Tag::of::<scrollable::State>()
+    }
+
+    fn state(&self) -> State {
+        State::new(scrollable::State::new())
+    }
+
     fn children(&self) -> Vec<Tree> {
-        vec![Tree::new(&self.container as &dyn Widget<_, _>)]
+        vec![Tree::new(&self.list as &dyn Widget<_, _>)]
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&[&self.container as &dyn Widget<_, _>]);
+        tree.diff_children(&[&self.list as &dyn Widget<_, _>]);
     }
 
     fn width(&self) -> Length {
@@ -204,8 +297,17 @@ where
 
         let limits = limits.max_width(max_width as f32 + self.padding * 2.0);
 
-        let content = self.container.layout(renderer, &limits);
-        let size = limits.resolve(content.size());
+        let mut content = scrollable::layout(
+            renderer,
+            &limits.loose().pad(BORDER),
+            Length::Shrink,
+            Length::Shrink,
+            &Direction::default(),
+            |renderer, limits| self.list.layout(renderer, limits),
+        );
+        content.move_to(Point::new(BORDER.left, BORDER.top));
+
+        let size = limits.resolve(content.size().pad(BORDER));
         Node::with_children(size, vec![content])
     }
 
@@ -218,21 +320,46 @@ where
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
-        viewport: &Rectangle,
+        _viewport: &Rectangle,
     ) -> event::Status {
-        self.container.on_event(
-            &mut state.children[0],
+        let status = scrollable::update(
+            state.state.downcast_mut::<scrollable::State>(),
             event,
             layout
                 .children()
                 .next()
                 .expect("Scrollable Child Missing in Selection List"),
             cursor,
-            renderer,
             clipboard,
             shell,
-            viewport,
-        )
+            Direction::default(),
+            &None,
+            |event, layout, cursor, clipboard, shell, viewport| {
+                self.list.on_event(
+                    &mut state.children[0],
+                    event,
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            },
+        );
+
+        // The list can not scroll itself, so it leaves the offset that brings
+        // the option navigated to with the keyboard into view for us.
+        let list_state = state.children[0].state.downcast_mut::<ListState>();
+
+        if let Some(y) = list_state.scroll_to.take() {
+            state
+                .state
+                .downcast_mut::<scrollable::State>()
+                .scroll_to(AbsoluteOffset { x: 0.0, y });
+        }
+
+        status
     }
 
     fn mouse_interaction(
@@ -240,11 +367,22 @@ where
         state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
-        viewport: &Rectangle,
+        _viewport: &Rectangle,
         renderer: &Renderer,
     ) -> mouse::Interaction {
-        self.container
-            .mouse_interaction(&state.children[0], layout, cursor, viewport, renderer)
+        scrollable::mouse_interaction(
+            state.state.downcast_ref::<scrollable::State>(),
+            layout
+                .children()
+                .next()
+                .expect("Scrollable Child Missing in Selection List"),
+            cursor,
+            Direction::default(),
+            |layout, cursor, viewport| {
+                self.list
+                    .mouse_interaction(&state.children[0], layout, cursor, viewport, renderer)
+            },
+        )
     }
 
     fn draw(
@@ -267,28 +405,88 @@ where
             theme.style(self.style).background,
         );
 
-        self.container.draw(
-            &state.children[0],
+        scrollable::draw(
+            state.state.downcast_ref::<scrollable::State>(),
             renderer,
             theme,
-            style,
             layout
                 .children()
                 .next()
                 .expect("Scrollable Child Missing in Selection List"),
             cursor,
-            &layout.bounds(),
+            Direction::default(),
+            &<Renderer::Theme as scrollable::StyleSheet>::Style::default(),
+            |renderer, layout, cursor, viewport| {
+                self.list.draw(
+                    &state.children[0],
+                    renderer,
+                    theme,
+                    style,
+                    layout,
+                    cursor,
+                    viewport,
+                );
+            },
         );
     }
 }
 
+/// The border between the [`SelectionList`] and the scrollable with its options.
+const BORDER: Padding = Padding::new(1.0);
+
+/// The state of the list inside of the tree of a [`SelectionList`].
+#[cfg(feature = "combo_box")]
+pub(crate) fn list_state(tree: &mut Tree) -> &mut ListState {
+    tree.children[0].state.downcast_mut::<ListState>()
+}
+
+/// Handles a key pressed while another widget, like the text input of a
+/// [`ComboBox`](crate::ComboBox), keeps the focus as if the [`SelectionList`]
+/// was focused, moving the highlighted option or selecting it.
+#[cfg(feature = "combo_box")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn navigate<'a, T, Message, Renderer>(
+    selection_list: &mut SelectionList<'a, T, Message, Renderer>,
+    tree: &mut Tree,
+    key_code: KeyCode,
+    layout: Layout<'_>,
+    renderer: &Renderer,
+    clipboard: &mut dyn Clipboard,
+    shell: &mut Shell<'_, Message>,
+) -> event::Status
+where
+    T: 'a + Clone + ToString + Eq + Hash,
+    Message: 'a,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font> + 'a,
+    Renderer::Theme: StyleSheet + container::StyleSheet + scrollable::StyleSheet,
+{
+    let was_focused = std::mem::replace(&mut list_state(tree).is_focused, true);
+
+    let status = selection_list.on_event(
+        tree,
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers: keyboard::Modifiers::default(),
+        }),
+        layout,
+        Cursor::Unavailable,
+        renderer,
+        clipboard,
+        shell,
+        &layout.bounds(),
+    );
+
+    list_state(tree).is_focused = was_focused;
+    status
+}
+
 impl<'a, T, Message, Renderer> From<SelectionList<'a, T, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    T: Clone + ToString + Eq + Hash,
-    Message: 'static,
+    T: 'a + Clone + ToString + Eq + Hash,
+    Message: 'a,
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
-    Renderer::Theme: StyleSheet + container::StyleSheet,
+    Renderer::Theme: StyleSheet + container::StyleSheet + scrollable::StyleSheet,
 {
     fn from(selection_list: SelectionList<'a, T, Message, Renderer>) -> Self {
         Element::new(selection_list)
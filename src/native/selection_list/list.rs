@@ -1,25 +1,32 @@
 //! Build and show dropdown `ListMenus`.
 use std::{
     borrow::Cow,
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, BTreeSet, HashMap},
     hash::{Hash, Hasher},
     marker::PhantomData,
+    ops::Range,
+    time::{Duration, Instant},
 };
 
-use crate::selection_list::StyleSheet;
+use crate::{
+    graphics::icons::{Icon, ICON_FONT},
+    selection_list::StyleSheet,
+};
 
 use iced_widget::{
     core::{
         self,
         alignment::{Horizontal, Vertical},
-        event, layout,
+        event,
+        keyboard::{self, KeyCode, Modifiers},
+        layout,
         mouse::{self, Cursor},
         renderer, touch,
         widget::{
             tree::{State, Tag},
             Tree,
         },
-        Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
+        window, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Size, Widget,
     },
     text::LineHeight,
 };
@@ -41,13 +48,27 @@ where
     /// Style for Font colors and Box hover colors.
     pub style: <Renderer::Theme as StyleSheet>::Style,
     /// Function Pointer On Select to call on Mouse button press.
-    pub on_selected: Box<dyn Fn((usize, T)) -> Message>,
+    pub on_selected: Box<dyn Fn((usize, T)) -> Message + 'a>,
+    /// Function Pointer called with every selected option whenever the selection
+    /// changes. Allows selecting multiple options if set.
+    #[allow(clippy::type_complexity)]
+    pub on_selection_change: Option<Box<dyn Fn(Vec<(usize, T)>) -> Message + 'a>>,
     /// The padding Width
     pub padding: f32,
     /// The Text Size
     pub text_size: f32,
+    /// The fixed height of every option. Defaults to `text_size + padding * 2`.
+    pub item_height: Option<f32>,
     /// Set the Selected ID manually.
     pub selected: Option<usize>,
+    /// Set the Selected IDs manually when multiple options can be selected.
+    pub selection: Option<Vec<usize>>,
+    /// Function Pointer called with the index an option was dragged from and
+    /// the index it was dropped at. Allows reordering the options if set.
+    pub on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    /// Builds the content of an option instead of drawing its label.
+    #[allow(clippy::type_complexity)]
+    pub view: Option<Box<dyn Fn(&T, ItemState) -> Element<'a, Message, Renderer> + 'a>>,
     /// Shadow Type holder for Renderer.
     pub phantomdata: PhantomData<Renderer>,
 }
@@ -59,6 +80,396 @@ pub struct ListState {
     pub hovered_option: Option<usize>,
     /// The index in the list of options of the last chosen Item Clicked for Processing
     pub last_selected_index: Option<(usize, u64)>,
+    /// The indices of the selected options when multiple options can be selected.
+    pub selection: BTreeSet<usize>,
+    /// The option a range selected with Shift starts at.
+    pub anchor: Option<usize>,
+    /// The currently pressed keyboard modifiers.
+    pub modifiers: Modifiers,
+    /// Whether the list receives keyboard input.
+    pub is_focused: bool,
+    /// The characters typed in quick succession to jump to an option.
+    pub type_ahead: String,
+    /// The lowercase labels of the options searched while typing.
+    pub type_ahead_labels: Vec<String>,
+    /// When the last character was typed.
+    pub last_typed: Option<Instant>,
+    /// The vertical offset the surrounding scrollable should scroll to.
+    pub scroll_to: Option<f32>,
+    /// The option that is being dragged to reorder it.
+    pub drag: Option<Drag>,
+    /// The trees of the visible options built by the view of the [`List`].
+    view_trees: ViewTrees,
+}
+
+/// The trees of the options built by the view of a [`List`], keyed by their index.
+///
+/// The trees are only a cache, so a clone starts out empty and builds them again.
+#[derive(Debug, Default)]
+struct ViewTrees(HashMap<usize, Tree>);
+
+impl Clone for ViewTrees {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+/// An option of a [`List`] that is being dragged to reorder it.
+#[derive(Debug, Clone, Copy)]
+pub struct Drag {
+    /// The index of the dragged option.
+    pub from: usize,
+    /// The vertical position the drag started at.
+    pub origin: f32,
+    /// The last known vertical position of the cursor inside of the list.
+    pub cursor: f32,
+    /// Whether the cursor moved far enough to start reordering.
+    pub is_dragging: bool,
+}
+
+/// The distance the cursor has to move before an option is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The distance to the edge of the viewport at which dragging scrolls the list.
+const AUTO_SCROLL_EDGE: f32 = 20.0;
+
+/// The distance the list scrolls every frame while dragging near an edge.
+const AUTO_SCROLL_STEP: f32 = 4.0;
+
+/// The state of an option passed to the view of a [`List`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemState {
+    /// The index of the option.
+    pub index: usize,
+    /// Whether the option is hovered or highlighted with the keyboard.
+    pub is_hovered: bool,
+    /// Whether the option is selected.
+    pub is_selected: bool,
+}
+
+/// The time after which typing starts a new type-ahead search.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl<T, Message, Renderer> List<'_, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq + Hash,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The height of a single option of the [`List`].
+    pub fn option_height(&self) -> f32 {
+        self.item_height
+            .unwrap_or(self.text_size + self.padding * 2.0)
+    }
+
+    /// The state of the option at `index` passed to the view of the [`List`].
+    fn item_state(&self, list_state: &ListState, index: usize) -> ItemState {
+        let is_selected = if self.on_selection_change.is_some() {
+            list_state.selection.contains(&index)
+        } else {
+            list_state
+                .last_selected_index
+                .is_some_and(|(selected, _)| selected == index)
+        };
+
+        ItemState {
+            index,
+            is_hovered: list_state.hovered_option == Some(index),
+            is_selected,
+        }
+    }
+
+    /// The index of the option at the given vertical offset into the [`List`].
+    fn option_at(&self, offset: f32) -> Option<usize> {
+        let index = (offset / self.option_height()) as usize;
+
+        (offset >= 0.0 && index < self.options.len()).then_some(index)
+    }
+
+    /// The range of options that are visible inside of the `viewport`.
+    fn visible_range(&self, bounds: Rectangle, viewport: &Rectangle) -> Range<usize> {
+        let option_height = self.option_height();
+        let offset = (viewport.y - bounds.y).max(0.0);
+        let start = ((offset / option_height) as usize).min(self.options.len());
+        let end = (((offset + viewport.height) / option_height).ceil() as usize)
+            .clamp(start, self.options.len());
+
+        start..end
+    }
+
+    /// Selects the option at `index` as if it was clicked.
+    fn select(
+        &self,
+        list_state: &mut ListState,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Some(option) = self.options.get(index) {
+            let mut hasher = DefaultHasher::new();
+            option.hash(&mut hasher);
+            list_state.last_selected_index = Some((index, hasher.finish()));
+        }
+
+        if self.on_selection_change.is_some() {
+            return self.click_multiple(list_state, index, shell);
+        }
+
+        list_state
+            .last_selected_index
+            .and_then(|(index, _)| self.options.get(index).map(|option| (index, option)))
+            .map_or(event::Status::Ignored, |(index, option)| {
+                shell.publish((self.on_selected)((index, option.clone())));
+                event::Status::Captured
+            })
+    }
+
+    /// Handles a key press while the [`List`] is focused.
+    fn on_key_pressed(
+        &self,
+        list_state: &mut ListState,
+        key_code: KeyCode,
+        modifiers: Modifiers,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let last = self.options.len().saturating_sub(1);
+        let current = list_state
+            .hovered_option
+            .or_else(|| list_state.last_selected_index.map(|(index, _)| index));
+        let page = ((viewport.height / self.option_height()) as usize).max(1);
+
+        let target = match key_code {
+            KeyCode::Up => current.map_or(0, |index| index.saturating_sub(1)),
+            KeyCode::Down => current.map_or(0, |index| (index + 1).min(last)),
+            KeyCode::PageUp => current.map_or(0, |index| index.saturating_sub(page)),
+            KeyCode::PageDown => current.map_or(0, |index| (index + page).min(last)),
+            KeyCode::Home => 0,
+            KeyCode::End => last,
+            KeyCode::Enter | KeyCode::NumpadEnter => {
+                return current.map_or(event::Status::Ignored, |index| {
+                    self.select(list_state, index, shell)
+                });
+            }
+            KeyCode::A if modifiers.command() && self.on_selection_change.is_some() => {
+                list_state.selection = (0..self.options.len()).collect();
+                self.publish_selection(list_state, shell);
+                return event::Status::Captured;
+            }
+            KeyCode::Escape => {
+                list_state.is_focused = false;
+                return event::Status::Captured;
+            }
+            _ => return event::Status::Ignored,
+        };
+
+        if self.options.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        self.focus_option(list_state, target, bounds, viewport);
+        event::Status::Captured
+    }
+
+    /// Jumps to the first option starting with the characters typed in quick
+    /// succession.
+    fn type_ahead(
+        &self,
+        list_state: &mut ListState,
+        c: char,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let now = Instant::now();
+
+        let continues_search = list_state
+            .last_typed
+            .is_some_and(|last| now.duration_since(last) <= TYPE_AHEAD_TIMEOUT);
+
+        if !continues_search {
+            list_state.type_ahead.clear();
+        }
+        list_state.type_ahead.extend(c.to_lowercase());
+        list_state.last_typed = Some(now);
+
+        // The labels are only collected once per search instead of on every
+        // typed character.
+        if !continues_search || list_state.type_ahead_labels.len() != self.options.len() {
+            list_state.type_ahead_labels = self
+                .options
+                .iter()
+                .map(|option| option.to_string().to_lowercase())
+                .collect();
+        }
+
+        let prefix = list_state.type_ahead.as_str();
+        let found = list_state
+            .type_ahead_labels
+            .iter()
+            .position(|label| label.starts_with(prefix));
+
+        if let Some(index) = found {
+            self.focus_option(list_state, index, bounds, viewport);
+        }
+
+        event::Status::Captured
+    }
+
+    /// Highlights the option at `index` and requests the surrounding
+    /// [`Scrollable`](iced_widget::Scrollable) to scroll it into view.
+    fn focus_option(
+        &self,
+        list_state: &mut ListState,
+        index: usize,
+        bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        let option_height = self.option_height();
+        #[allow(clippy::cast_precision_loss)]
+        let top = option_height * index as f32;
+        let visible_top = viewport.y - bounds.y;
+
+        list_state.hovered_option = Some(index);
+        list_state.scroll_to = if top < visible_top {
+            Some(top)
+        } else if top + option_height > visible_top + viewport.height {
+            Some(top + option_height - viewport.height)
+        } else {
+            None
+        };
+    }
+
+    /// The index the dragged option would be inserted at if dropped now.
+    fn drop_index(&self, drag: &Drag) -> usize {
+        ((drag.cursor / self.option_height()).round().max(0.0) as usize).min(self.options.len())
+    }
+
+    /// Updates the option being dragged to reorder it.
+    fn on_drag(
+        &self,
+        list_state: &mut ListState,
+        event: &Event,
+        bounds: Rectangle,
+        cursor: Option<f32>,
+        viewport: &Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(drag) = list_state.drag.as_mut() else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(cursor) = cursor {
+                    drag.cursor = cursor - bounds.y;
+                    drag.is_dragging |= (drag.cursor - drag.origin).abs() > DRAG_THRESHOLD;
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(_)) if drag.is_dragging => {}
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let drag = *drag;
+                list_state.drag = None;
+
+                if !drag.is_dragging {
+                    return event::Status::Ignored;
+                }
+
+                let target = self.drop_index(&drag);
+                let to = if target > drag.from {
+                    target - 1
+                } else {
+                    target
+                };
+
+                if let Some(on_reorder) = &self.on_reorder {
+                    if to != drag.from {
+                        shell.publish(on_reorder(drag.from, to));
+                    }
+                }
+
+                return event::Status::Captured;
+            }
+            _ => return event::Status::Ignored,
+        }
+
+        if !drag.is_dragging {
+            return event::Status::Ignored;
+        }
+
+        // Scroll while the cursor rests near the top or bottom edge of the viewport.
+        let visible_top = viewport.y - bounds.y;
+        let visible_bottom = visible_top + viewport.height;
+        let max_offset = (bounds.height - viewport.height).max(0.0);
+
+        let delta = if drag.cursor < visible_top + AUTO_SCROLL_EDGE {
+            -AUTO_SCROLL_STEP.min(visible_top)
+        } else if drag.cursor > visible_bottom - AUTO_SCROLL_EDGE {
+            AUTO_SCROLL_STEP.min(max_offset - visible_top)
+        } else {
+            0.0
+        };
+
+        if delta.abs() > f32::EPSILON {
+            drag.cursor += delta;
+            list_state.scroll_to = Some(visible_top + delta);
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        event::Status::Captured
+    }
+
+    /// Publishes every selected option if multiple options can be selected.
+    fn publish_selection(&self, list_state: &ListState, shell: &mut Shell<'_, Message>) {
+        if let Some(on_selection_change) = &self.on_selection_change {
+            let selection = list_state
+                .selection
+                .iter()
+                .filter_map(|&index| {
+                    self.options
+                        .get(index)
+                        .map(|option| (index, option.clone()))
+                })
+                .collect();
+
+            shell.publish(on_selection_change(selection));
+        }
+    }
+
+    /// Updates the selection after the option at `index` was clicked while
+    /// multiple options can be selected.
+    ///
+    /// A click with Ctrl toggles the option, a click with Shift selects the range
+    /// from the last clicked option and any other click selects only the option.
+    fn click_multiple(
+        &self,
+        list_state: &mut ListState,
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(option) = self.options.get(index) else {
+            return event::Status::Ignored;
+        };
+
+        if list_state.modifiers.command() {
+            if !list_state.selection.remove(&index) {
+                let _ = list_state.selection.insert(index);
+            }
+            list_state.anchor = Some(index);
+        } else if list_state.modifiers.shift() {
+            let anchor = *list_state.anchor.get_or_insert(index);
+            list_state.selection = (anchor.min(index)..=anchor.max(index)).collect();
+        } else {
+            list_state.selection = BTreeSet::from([index]);
+            list_state.anchor = Some(index);
+            shell.publish((self.on_selected)((index, option.clone())));
+        }
+
+        self.publish_selection(list_state, shell);
+        event::Status::Captured
+    }
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer> for List<'a, T, Message, Renderer>
@@ -78,6 +489,32 @@ where
     fn diff(&self, state: &mut Tree) {
         let list_state = state.state.downcast_mut::<ListState>();
 
+        if let Some(view) = &self.view {
+            let mut trees = std::mem::take(&mut list_state.view_trees.0);
+
+            trees.retain(|index, _| *index < self.options.len());
+            for (index, tree) in &mut trees {
+                tree.diff(view(
+                    &self.options[*index],
+                    self.item_state(list_state, *index),
+                ));
+            }
+
+            list_state.view_trees.0 = trees;
+        }
+
+        if let Some(selection) = &self.selection {
+            list_state.selection = selection
+                .iter()
+                .copied()
+                .filter(|index| *index < self.options.len())
+                .collect();
+        } else {
+            list_state
+                .selection
+                .retain(|index| *index < self.options.len());
+        }
+
         if let Some(id) = self.selected {
             if let Some(option) = self.options.get(id) {
                 let mut hasher = DefaultHasher::new();
@@ -116,7 +553,7 @@ where
         #[allow(clippy::cast_precision_loss)]
         let intrinsic = Size::new(
             limits.fill().width,
-            (self.text_size + self.padding * 2.0) * self.options.len() as f32,
+            self.option_height() * self.options.len() as f32,
         );
 
         layout::Node::new(intrinsic)
@@ -131,45 +568,86 @@ where
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         shell: &mut Shell<Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
         let bounds = layout.bounds();
         let mut status = event::Status::Ignored;
         let list_state = state.state.downcast_mut::<ListState>();
+        let is_over = cursor.is_over(bounds);
+        let cursor_y = cursor.position().map(|position| position.y);
         let cursor = cursor.position().unwrap_or_default();
 
+        if let Some(view) = &self.view {
+            let visible = self.visible_range(bounds, viewport);
+            let mut trees = std::mem::take(&mut list_state.view_trees.0);
+
+            trees.retain(|index, _| visible.contains(index));
+            for index in visible {
+                let element = view(&self.options[index], self.item_state(list_state, index));
+
+                trees
+                    .entry(index)
+                    .or_insert_with(|| Tree::new(&element))
+                    .diff(&element);
+            }
+
+            list_state.view_trees.0 = trees;
+        }
+
+        if list_state.drag.is_some() {
+            let status = self.on_drag(list_state, &event, bounds, cursor_y, viewport, shell);
+
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                list_state.modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                list_state.is_focused = is_over;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if list_state.is_focused => {
+                return self
+                    .on_key_pressed(list_state, key_code, modifiers, bounds, viewport, shell);
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if list_state.is_focused && !c.is_control() =>
+            {
+                return self.type_ahead(list_state, c, bounds, viewport);
+            }
+            _ => {}
+        }
+
         if bounds.contains(cursor) {
             match event {
                 Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    list_state.hovered_option = Some(
-                        ((cursor.y - bounds.y) / (self.text_size + (self.padding * 2.0))) as usize,
-                    );
+                    list_state.hovered_option = self.option_at(cursor.y - bounds.y);
                 }
                 Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    list_state.hovered_option = Some(
-                        ((cursor.y - bounds.y) / (self.text_size + (self.padding * 2.0))) as usize,
-                    );
+                    list_state.hovered_option = self.option_at(cursor.y - bounds.y);
 
-                    if let Some(index) = list_state.hovered_option {
-                        if let Some(option) = self.options.get(index) {
-                            let mut hasher = DefaultHasher::new();
-                            option.hash(&mut hasher);
-                            list_state.last_selected_index = Some((index, hasher.finish()));
-                        }
+                    if let (Some(index), Some(_)) = (list_state.hovered_option, &self.on_reorder) {
+                        list_state.drag = Some(Drag {
+                            from: index,
+                            origin: cursor.y - bounds.y,
+                            cursor: cursor.y - bounds.y,
+                            is_dragging: false,
+                        });
                     }
 
-                    status =
-                        list_state
-                            .last_selected_index
-                            .map_or(event::Status::Ignored, |last| {
-                                if let Some(option) = self.options.get(last.0) {
-                                    shell.publish((self.on_selected)((last.0, option.clone())));
-                                    event::Status::Captured
-                                } else {
-                                    event::Status::Ignored
-                                }
-                            });
+                    status = list_state
+                        .hovered_option
+                        .map_or(event::Status::Ignored, |index| {
+                            self.select(list_state, index, shell)
+                        });
                 }
                 _ => {}
             }
@@ -180,15 +658,18 @@ where
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
+        let list_state = state.state.downcast_ref::<ListState>();
 
-        if bounds.contains(cursor.position().unwrap_or_default()) {
+        if list_state.drag.is_some_and(|drag| drag.is_dragging) {
+            mouse::Interaction::Grabbing
+        } else if bounds.contains(cursor.position().unwrap_or_default()) {
             mouse::Interaction::Pointer
         } else {
             mouse::Interaction::default()
@@ -202,33 +683,35 @@ where
         theme: &Renderer::Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: Cursor,
+        cursor: Cursor,
         viewport: &Rectangle,
     ) {
         use std::f32;
 
         let bounds = layout.bounds();
-        let option_height = self.text_size + (self.padding * 2.0);
-        let offset = viewport.y - bounds.y;
-        let start = (offset / option_height) as usize;
-        let end = ((offset + viewport.height) / option_height).ceil() as usize;
+        let option_height = self.option_height();
+        let visible = self.visible_range(bounds, viewport);
+        let start = visible.start;
 
-        let visible_options = &self.options[start..end.min(self.options.len())];
+        let visible_options = &self.options[visible];
         let list_state = state.state.downcast_ref::<ListState>();
+        let appearance = theme.style(self.style);
+        let checkbox_size = self.text_size.min(option_height);
 
         for (i, option) in visible_options.iter().enumerate() {
             let i = start + i;
-            let is_selected = list_state
-                .last_selected_index
-                .map(|u| u.0 == i)
-                .unwrap_or_default();
-            let is_hovered = list_state.hovered_option == Some(i);
+            let item_state = self.item_state(list_state, i);
+            let ItemState {
+                is_selected,
+                is_hovered,
+                ..
+            } = item_state;
 
             let bounds = Rectangle {
                 x: bounds.x,
                 y: bounds.y + option_height * i as f32,
                 width: bounds.width,
-                height: self.text_size + (self.padding * 2.0),
+                height: option_height,
             };
 
             if is_selected || is_hovered {
@@ -240,37 +723,135 @@ where
                         border_color: Color::TRANSPARENT,
                     },
                     if is_selected {
-                        theme.style(self.style).selected_background
+                        appearance.selected_background
                     } else {
-                        theme.style(self.style).hovered_background
+                        appearance.hovered_background
                     },
                 );
             }
 
             let text_color = if is_selected {
-                theme.style(self.style).selected_text_color
+                appearance.selected_text_color
             } else if is_hovered {
-                theme.style(self.style).hovered_text_color
+                appearance.hovered_text_color
             } else {
-                theme.style(self.style).text_color
+                appearance.text_color
             };
 
-            renderer.fill_text(core::text::Text {
-                content: &option.to_string(),
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: bounds.center_y(),
-                    width: f32::INFINITY,
-                    ..bounds
+            let text_x = if appearance.checkbox {
+                let checkbox_bounds = Rectangle {
+                    x: bounds.x + self.padding,
+                    y: bounds.center_y() - checkbox_size / 2.0,
+                    width: checkbox_size,
+                    height: checkbox_size,
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: checkbox_bounds,
+                        border_radius: (2.0).into(),
+                        border_width: 1.0,
+                        border_color: appearance.checkbox_border_color,
+                    },
+                    appearance.checkbox_background,
+                );
+
+                if is_selected {
+                    renderer.fill_text(core::text::Text {
+                        content: &char::from(Icon::Check).to_string(),
+                        bounds: Rectangle {
+                            x: checkbox_bounds.center_x(),
+                            y: checkbox_bounds.center_y(),
+                            ..checkbox_bounds
+                        },
+                        size: checkbox_size,
+                        color: appearance.checkmark_color,
+                        font: ICON_FONT,
+                        horizontal_alignment: Horizontal::Center,
+                        vertical_alignment: Vertical::Center,
+                        line_height: LineHeight::default(),
+                        shaping: iced_widget::text::Shaping::Basic,
+                    });
+                }
+
+                checkbox_bounds.x + checkbox_size + self.padding
+            } else {
+                bounds.x
+            };
+
+            if let Some(view) = &self.view {
+                let element = view(option, item_state);
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (bounds.x + bounds.width - text_x - self.padding).max(0.0),
+                        option_height,
+                    ),
+                );
+                let mut node = element.as_widget().layout(renderer, &limits);
+                node.move_to(
+                    [
+                        text_x,
+                        bounds.y + (option_height - node.size().height) / 2.0,
+                    ]
+                    .into(),
+                );
+
+                let mut draw = |tree: &Tree| {
+                    element.as_widget().draw(
+                        tree,
+                        renderer,
+                        theme,
+                        &renderer::Style { text_color },
+                        Layout::new(&node),
+                        cursor,
+                        viewport,
+                    );
+                };
+
+                // Options that became visible since the last event have no tree yet.
+                match list_state.view_trees.0.get(&i) {
+                    Some(tree) => draw(tree),
+                    None => draw(&Tree::new(&element)),
+                }
+            } else {
+                renderer.fill_text(core::text::Text {
+                    content: &option.to_string(),
+                    bounds: Rectangle {
+                        x: text_x,
+                        y: bounds.center_y(),
+                        width: f32::INFINITY,
+                        ..bounds
+                    },
+                    size: self.text_size,
+                    color: text_color,
+                    font: self.font,
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    line_height: LineHeight::default(),
+                    shaping: iced_widget::text::Shaping::Advanced,
+                });
+            }
+        }
+
+        if let Some(drag) = list_state.drag.filter(|drag| drag.is_dragging) {
+            #[allow(clippy::cast_precision_loss)]
+            let y = bounds.y + option_height * self.drop_index(&drag) as f32;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x,
+                        y: (y - 1.0).max(bounds.y),
+                        width: bounds.width,
+                        height: 2.0,
+                    },
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
                 },
-                size: self.text_size,
-                color: text_color,
-                font: self.font,
-                horizontal_alignment: Horizontal::Left,
-                vertical_alignment: Vertical::Center,
-                line_height: LineHeight::default(),
-                shaping: iced_widget::text::Shaping::Advanced,
-            });
+                appearance.drop_indicator_color,
+            );
         }
     }
 }
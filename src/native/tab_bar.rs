@@ -8,22 +8,30 @@ use iced_widget::{
     core::{
         self,
         alignment::{self, Horizontal, Vertical},
-        event, layout,
-        mouse::{self, Cursor},
-        renderer, touch,
-        widget::Tree,
-        Alignment, Clipboard, Color, Element, Event, Layout, Length, Rectangle, Shell, Widget,
+        event,
+        keyboard::{self, KeyCode},
+        layout,
+        mouse::{self, Cursor, ScrollDelta},
+        overlay, renderer, touch,
+        widget::{operation::Focusable, tree, Operation, Tree},
+        Alignment, Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size,
+        Widget,
     },
     runtime::Font,
     text::{self, LineHeight},
     Column, Row, Text,
 };
 
+pub mod orientation;
+pub mod overflow;
 pub mod tab_label;
 pub use crate::style::tab_bar::{Appearance, StyleSheet};
+pub use orientation::Orientation;
+pub use overflow::Overflow;
 pub use tab_label::TabLabel;
 
 use crate::graphics::icons;
+use crate::native::overlay::TabBarOverlay;
 
 use std::marker::PhantomData;
 /// The default icon size.
@@ -36,9 +44,22 @@ const DEFAULT_CLOSE_SIZE: f32 = 16.0;
 const DEFAULT_PADDING: f32 = 5.0;
 /// The default spacing around the tabs.
 const DEFAULT_SPACING: f32 = 0.0;
+/// The distance the cursor has to move before a tab is dragged.
+const DRAG_THRESHOLD: f32 = 4.0;
+/// The size of the buttons scrolling the tabs or listing the hidden tabs
+/// along the tab bar.
+const OVERFLOW_BUTTON_SIZE: f32 = 24.0;
+/// The distance the tabs are scrolled by for every line of the mouse wheel.
+const SCROLL_LINE: f32 = 40.0;
 
 /// A tab bar to show tabs.
 ///
+/// A [`TabBar`](TabBar) is focused by clicking on it. While it is focused, the
+/// arrow keys along its [`Orientation`](Orientation) select the previous or
+/// next tab and Home and End select the first or last tab. Ctrl+Tab and
+/// Ctrl+Shift+Tab cycle through the tabs and Ctrl+W closes the active tab if
+/// [`on_close`](TabBar::on_close) is set.
+///
 /// # Example
 /// ```ignore
 /// # use iced_aw::{TabLabel, TabBar};
@@ -64,7 +85,7 @@ const DEFAULT_SPACING: f32 = 0.0;
 /// .set_active_tab(&TabId::One);
 /// ```
 #[allow(missing_debug_implementations)]
-pub struct TabBar<Message, TabId, Renderer = crate::Renderer>
+pub struct TabBar<'a, Message, TabId, Renderer = crate::Renderer>
 where
     Renderer: core::Renderer + core::text::Renderer,
     Renderer::Theme: StyleSheet,
@@ -73,13 +94,15 @@ where
     /// The index of the currently active tab.
     active_tab: usize,
     /// The vector containing the labels of the tabs.
-    tab_labels: Vec<TabLabel>,
+    tab_labels: Vec<TabLabel<'a, Renderer>>,
     /// The vector containing the indices of the tabs.
     tab_indices: Vec<TabId>,
     /// The function that produces the message when a tab is selected.
     on_select: Box<dyn Fn(TabId) -> Message>,
     /// The function that produces the message when the close icon was pressed.
     on_close: Option<Box<dyn Fn(TabId) -> Message>>,
+    /// The function that produces the message when a tab was dragged to a new index.
+    on_reorder: Option<Box<dyn Fn(TabId, usize) -> Message>>,
     /// The width of the [`TabBar`](TabBar).
     width: Length,
     /// The width of the tabs of the [`TabBar`](TabBar).
@@ -98,6 +121,12 @@ where
     padding: f32,
     /// The spacing of the tabs of the [`TabBar`](TabBar).
     spacing: f32,
+    /// How the [`TabBar`](TabBar) shows tabs that don't fit into its width.
+    overflow: Overflow,
+    /// The direction the tabs of the [`TabBar`](TabBar) are laid out in.
+    orientation: Orientation,
+    /// The horizontal alignment of the labels inside of the tabs.
+    label_alignment: Horizontal,
     /// The optional icon font of the [`TabBar`](TabBar).
     icon_font: Option<Font>,
     /// The optional text font of the [`TabBar`](TabBar).
@@ -108,7 +137,7 @@ where
     _renderer: PhantomData<Renderer>,
 }
 
-impl<Message, TabId, Renderer> TabBar<Message, TabId, Renderer>
+impl<'a, Message, TabId, Renderer> TabBar<'a, Message, TabId, Renderer>
 where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: StyleSheet,
@@ -136,7 +165,10 @@ where
     ///     * a vector containing the [`TabLabel`](TabLabel)s of the [`TabBar`](TabBar).
     ///     * the function that will be called if a tab is selected by the user.
     ///         It takes the index of the selected tab.
-    pub fn with_tab_labels<F>(tab_labels: Vec<(TabId, TabLabel)>, on_select: F) -> Self
+    pub fn with_tab_labels<F>(
+        tab_labels: Vec<(TabId, TabLabel<'a, Renderer>)>,
+        on_select: F,
+    ) -> Self
     where
         F: 'static + Fn(TabId) -> Message,
     {
@@ -146,6 +178,7 @@ where
             tab_labels: tab_labels.into_iter().map(|(_, label)| label).collect(),
             on_select: Box::new(on_select),
             on_close: None,
+            on_reorder: None,
             width: Length::Fill,
             tab_width: Length::Fill,
             height: Length::Shrink,
@@ -155,6 +188,9 @@ where
             close_size: DEFAULT_CLOSE_SIZE,
             padding: DEFAULT_PADDING,
             spacing: DEFAULT_SPACING,
+            overflow: Overflow::default(),
+            orientation: Orientation::default(),
+            label_alignment: Horizontal::Center,
             icon_font: None,
             text_font: None,
             style: <Renderer::Theme as StyleSheet>::Style::default(),
@@ -193,6 +229,23 @@ where
         self
     }
 
+    /// Sets the message that will be produced when a tab on the
+    /// [`TabBar`](TabBar) is dragged to a new position.
+    ///
+    /// Setting this allows reordering the tabs by dragging them. The dragged tab
+    /// follows the cursor and a line shows where it will be dropped. The given
+    /// function is called with the id of the tab and the index it should have
+    /// after it was removed from its old index. The order of the tabs is kept by
+    /// the application.
+    #[must_use]
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(TabId, usize) -> Message,
+    {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
     /// Sets the width of the [`TabBar`](TabBar).
     #[must_use]
     pub fn width(mut self, width: Length) -> Self {
@@ -271,6 +324,49 @@ where
         self
     }
 
+    /// Sets how the [`TabBar`](TabBar) shows tabs that don't fit into its width.
+    ///
+    /// The tabs are shrunk by default. When they are scrolled instead, the active
+    /// tab is kept scrolled into view.
+    #[must_use]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the [`Orientation`](Orientation) of the [`TabBar`](TabBar).
+    ///
+    /// A vertical [`TabBar`](TabBar) lays out its tabs from top to bottom and
+    /// is as wide as its widest tab while filling the available height, unless
+    /// its width and height were changed from their defaults before. When it
+    /// is focused, Up and Down select the previous and next tab instead of
+    /// Left and Right.
+    #[must_use]
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        let defaults = match self.orientation {
+            Orientation::Horizontal => (Length::Fill, Length::Shrink),
+            Orientation::Vertical => (Length::Shrink, Length::Fill),
+        };
+
+        if orientation != self.orientation && (self.width, self.height) == defaults {
+            self.width = defaults.1;
+            self.height = defaults.0;
+        }
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the horizontal alignment of the labels inside of the tabs of the
+    /// [`TabBar`](TabBar).
+    ///
+    /// The labels are centered by default. Vertical tab bars usually align
+    /// their labels to the left.
+    #[must_use]
+    pub fn label_alignment(mut self, alignment: Horizontal) -> Self {
+        self.label_alignment = alignment;
+        self
+    }
+
     /// Sets the font of the icons of the
     /// [`TabLabel`](crate::tab_bar::TabLabel)s of the [`TabBar`](TabBar).
     #[must_use]
@@ -296,7 +392,7 @@ where
 
     /// Pushes a [`TabLabel`](crate::tab_bar::TabLabel) to the [`TabBar`](TabBar).
     #[must_use]
-    pub fn push(mut self, id: TabId, tab_label: TabLabel) -> Self {
+    pub fn push(mut self, id: TabId, tab_label: TabLabel<'a, Renderer>) -> Self {
         self.tab_labels.push(tab_label);
         self.tab_indices.push(id);
         self
@@ -312,14 +408,642 @@ where
             .map_or(0, |a| a);
         self
     }
+
+    /// Handles the keyboard shortcuts of the [`TabBar`](TabBar), which also
+    /// work while the content of [`Tabs`](crate::native::tabs::Tabs) is focused.
+    ///
+    /// Ctrl+Tab and Ctrl+Shift+Tab cycle through the tabs and Ctrl+W (Cmd+W on
+    /// macOS) closes the active tab if [`on_close`](TabBar::on_close) is set.
+    pub(crate) fn on_shortcut(
+        &self,
+        key_code: KeyCode,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Some(index) = cycled_tab(key_code, modifiers, self.active_tab, self.size()) {
+            shell.publish((self.on_select)(self.tab_indices[index].clone()));
+            return event::Status::Captured;
+        }
+
+        match (&self.on_close, self.get_active_tab_id()) {
+            (Some(on_close), Some(id)) if key_code == KeyCode::W && modifiers.command() => {
+                shell.publish(on_close(id.clone()));
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Handles a key press while the [`TabBar`](TabBar) is focused.
+    fn on_key_pressed(
+        &self,
+        state: &mut State,
+        key_code: KeyCode,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if self.on_shortcut(key_code, modifiers, shell) == event::Status::Captured {
+            return event::Status::Captured;
+        }
+
+        if key_code == KeyCode::Escape {
+            state.is_focused = false;
+            return event::Status::Captured;
+        }
+
+        let Some(index) = navigated_tab(key_code, self.orientation, self.active_tab, self.size())
+        else {
+            return event::Status::Ignored;
+        };
+
+        if index != self.active_tab {
+            shell.publish((self.on_select)(self.tab_indices[index].clone()));
+        }
+        event::Status::Captured
+    }
+
+    /// Updates the tab being dragged to reorder it.
+    fn on_drag(
+        &self,
+        state: &mut State,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let Some(drag) = state.drag.as_mut() else {
+            return event::Status::Ignored;
+        };
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(position) = cursor.position() {
+                    drag.cursor = position;
+                    drag.is_dragging |= position.distance(drag.origin) > DRAG_THRESHOLD;
+                }
+
+                if drag.is_dragging {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                let drag = *drag;
+                state.drag = None;
+
+                if !drag.is_dragging {
+                    return event::Status::Ignored;
+                }
+
+                let target = drop_index(layout, drag.cursor, self.orientation);
+                let to = if target > drag.from {
+                    target - 1
+                } else {
+                    target
+                };
+
+                if let (Some(on_reorder), Some(id)) =
+                    (&self.on_reorder, self.tab_indices.get(drag.from))
+                {
+                    if to != drag.from {
+                        shell.publish(on_reorder(id.clone(), to));
+                    }
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Creates the node of a [`TabBar`](TabBar) with the given size from the
+    /// node of its tabs, making room for the button scrolling to the start of
+    /// the tabs if they overflow the [`TabBar`](TabBar).
+    fn scrolled_node(&self, size: Size, content: &layout::Node) -> layout::Node {
+        let orientation = self.orientation;
+        let inset = if self.overflow == Overflow::Scroll
+            && overflows(
+                orientation.length(Rectangle::with_size(content.size())),
+                orientation.length(Rectangle::with_size(size)),
+            ) {
+            OVERFLOW_BUTTON_SIZE
+        } else {
+            0.0
+        };
+
+        layout::Node::with_children(
+            size,
+            content
+                .children()
+                .iter()
+                .cloned()
+                .map(|mut tab| {
+                    tab.move_to(tab.bounds().position() + orientation.vector(inset));
+                    tab
+                })
+                .collect(),
+        )
+    }
+
+    /// The area the tabs are scrolled in if they overflow the [`TabBar`](TabBar).
+    fn scroll_area(&self, layout: Layout<'_>) -> Option<Rectangle> {
+        let bounds = layout.bounds();
+        let orientation = self.orientation;
+        let (start, end) = match self.overflow {
+            Overflow::Shrink => return None,
+            Overflow::Scroll => (OVERFLOW_BUTTON_SIZE, OVERFLOW_BUTTON_SIZE),
+            Overflow::Dropdown => (0.0, OVERFLOW_BUTTON_SIZE),
+        };
+
+        overflows(
+            content_length(layout, orientation),
+            orientation.length(bounds),
+        )
+        .then(|| {
+            orientation.slice(
+                bounds,
+                orientation.start(bounds) + start,
+                (orientation.length(bounds) - start - end).max(0.0),
+            )
+        })
+    }
+
+    /// The buttons before and after the area the tabs are scrolled in.
+    ///
+    /// The button before the area only exists if the tabs are scrolled with
+    /// arrow buttons, otherwise the button after the area lists the hidden tabs.
+    fn overflow_buttons(
+        &self,
+        bounds: Rectangle,
+        area: Rectangle,
+    ) -> (Option<Rectangle>, Rectangle) {
+        let orientation = self.orientation;
+        let end = orientation.slice(
+            bounds,
+            orientation.start(area) + orientation.length(area),
+            OVERFLOW_BUTTON_SIZE,
+        );
+        let start = (self.overflow == Overflow::Scroll)
+            .then(|| orientation.slice(bounds, orientation.start(bounds), OVERFLOW_BUTTON_SIZE));
+
+        (start, end)
+    }
+
+    /// The distance the tabs are scrolled by.
+    ///
+    /// If the active tab changed since the tabs were last scrolled, they are
+    /// scrolled until the active tab is completely visible.
+    fn scroll_offset(&self, state: &State, layout: Layout<'_>, area: Rectangle) -> f32 {
+        let orientation = self.orientation;
+        let mut offset = state.offset;
+
+        if let Some(tab) = layout
+            .children()
+            .nth(self.active_tab)
+            .filter(|_| state.scrolled_to != Some(self.active_tab))
+        {
+            let tab = tab.bounds();
+            let start = orientation.start(tab) - orientation.start(area);
+            let end = start + orientation.length(tab);
+
+            if start < offset {
+                offset = start;
+            } else if end > offset + orientation.length(area) {
+                offset = end - orientation.length(area);
+            }
+        }
+
+        offset.clamp(0.0, max_offset(layout, area, orientation))
+    }
+
+    /// Scrolls the tabs until the active tab is completely visible if it
+    /// changed since the tabs were last scrolled.
+    fn scroll_into_view(&self, state: &mut State, layout: Layout<'_>, area: Rectangle) {
+        state.offset = self.scroll_offset(state, layout, area);
+        state.scrolled_to = Some(self.active_tab);
+    }
+
+    /// Handles a press on the buttons next to the tabs that overflow the
+    /// [`TabBar`](TabBar) and returns true if a button was pressed.
+    fn on_overflow_press(
+        &self,
+        state: &mut State,
+        layout: Layout<'_>,
+        area: Rectangle,
+        cursor: Cursor,
+    ) -> bool {
+        let orientation = self.orientation;
+        let (start, end) = self.overflow_buttons(layout.bounds(), area);
+        let max_offset = max_offset(layout, area, orientation);
+
+        if start.is_some_and(|start| cursor.is_over(start)) {
+            // scroll to the start of the previous tab
+            state.offset = layout
+                .children()
+                .map(|tab| orientation.start(tab.bounds()) - orientation.start(area))
+                .filter(|start| *start < state.offset - 0.5)
+                .last()
+                .unwrap_or(0.0);
+        } else if cursor.is_over(end) {
+            if start.is_some() {
+                // scroll to the end of the next tab
+                state.offset = layout
+                    .children()
+                    .map(|tab| {
+                        orientation.start(tab.bounds()) + orientation.length(tab.bounds())
+                            - orientation.start(area)
+                            - orientation.length(area)
+                    })
+                    .find(|end| *end > state.offset + 0.5)
+                    .unwrap_or(max_offset)
+                    .min(max_offset);
+            } else {
+                state.dropdown_open = !state.dropdown_open;
+                state.dropdown_offset = 0.0;
+            }
+        } else {
+            return false;
+        }
+
+        true
+    }
+
+    /// Draws the tabs, the tab being dragged and where it will be dropped.
+    fn draw_tabs(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        clip: Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        for (((i, tab), tree), layout) in self
+            .tab_labels
+            .iter()
+            .enumerate()
+            .zip(&tree.children)
+            .zip(layout.children())
+        {
+            draw_tab(
+                renderer,
+                tab,
+                tree,
+                layout,
+                theme,
+                self.style,
+                i == self.get_active_tab_idx(),
+                cursor,
+                (self.icon_font.unwrap_or(icons::ICON_FONT), self.icon_size),
+                (self.text_font.unwrap_or_default(), self.text_size),
+                self.close_size,
+                self.label_alignment,
+            );
+        }
+
+        if state.is_focused {
+            if let Some(tab) = layout.children().nth(self.active_tab) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tab.bounds(),
+                        border_radius: (0.0).into(),
+                        border_width: 2.0,
+                        border_color: theme.active(self.style, true).focus_color,
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+        }
+
+        let Some(drag) = state.drag.filter(|drag| drag.is_dragging) else {
+            return;
+        };
+        let (Some(tab), Some(tab_tree), Some(tab_layout)) = (
+            self.tab_labels.get(drag.from),
+            tree.children.get(drag.from),
+            layout.children().nth(drag.from),
+        ) else {
+            return;
+        };
+
+        // the dragged tab follows the cursor along the tab bar
+        let orientation = self.orientation;
+        renderer.with_layer(clip, |renderer| {
+            renderer.with_translation(
+                orientation.vector(
+                    orientation.coordinate(drag.cursor) - orientation.coordinate(drag.origin),
+                ),
+                |renderer| {
+                    draw_tab(
+                        renderer,
+                        tab,
+                        tab_tree,
+                        tab_layout,
+                        theme,
+                        self.style,
+                        drag.from == self.get_active_tab_idx(),
+                        cursor,
+                        (self.icon_font.unwrap_or(icons::ICON_FONT), self.icon_size),
+                        (self.text_font.unwrap_or_default(), self.text_size),
+                        self.close_size,
+                        self.label_alignment,
+                    );
+                },
+            );
+        });
+
+        let bounds = layout.bounds();
+        let target = drop_index(layout, drag.cursor, orientation);
+        let position = layout.children().nth(target).map_or_else(
+            || {
+                layout.children().last().map_or_else(
+                    || orientation.start(bounds),
+                    |tab| orientation.start(tab.bounds()) + orientation.length(tab.bounds()),
+                )
+            },
+            |tab| orientation.start(tab.bounds()),
+        );
+
+        renderer.with_layer(clip, |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: orientation.slice(
+                        bounds,
+                        (position - 1.0).max(orientation.start(clip)),
+                        2.0,
+                    ),
+                    border_radius: (0.0).into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                theme.active(self.style, false).drop_indicator_color,
+            );
+        });
+    }
+
+    /// The height of an entry of the list of the hidden tabs.
+    fn dropdown_entry_height(&self) -> f32 {
+        self.text_size * 1.3 + 2.0 * self.padding
+    }
+
+    /// Lays out the element of a label inside of an entry of the list of the hidden tabs.
+    fn dropdown_element_node(
+        &self,
+        renderer: &Renderer,
+        element: &Element<'_, (), Renderer>,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(f32::INFINITY, self.dropdown_entry_height()),
+        );
+        element.as_widget().layout(renderer, &limits)
+    }
+
+    /// The size of the list of the hidden tabs.
+    pub(crate) fn dropdown_size(&self, renderer: &Renderer, hidden: &[usize]) -> Size {
+        let width = hidden
+            .iter()
+            .filter_map(|&i| self.tab_labels.get(i))
+            .map(|label| {
+                if let TabLabel::Element(element) = label {
+                    let node = self.dropdown_element_node(renderer, element);
+                    return node.size().width + 2.0 * self.padding;
+                }
+
+                let (icon, text) = dropdown_label(label);
+                let icon_width = icon.map_or(0.0, |_| self.text_size * 1.3 + self.padding);
+                let text_width = renderer.measure_width(
+                    text,
+                    self.text_size,
+                    self.text_font.unwrap_or_default(),
+                    iced_widget::text::Shaping::Advanced,
+                );
+
+                icon_width + text_width + 2.0 * self.padding
+            })
+            .fold(OVERFLOW_BUTTON_SIZE * 4.0, f32::max);
+
+        #[allow(clippy::cast_precision_loss)]
+        Size::new(width, self.dropdown_entry_height() * hidden.len() as f32)
+    }
+
+    /// Processes the events of the list of the hidden tabs.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn dropdown_on_event(
+        &self,
+        tree: &mut Tree,
+        event: &Event,
+        bounds: Rectangle,
+        button: Rectangle,
+        hidden: &[usize],
+        cursor: Cursor,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                state.dropdown_open = false;
+
+                let Some(position) = cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if bounds.contains(position) {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let entry = ((position.y - bounds.y + state.dropdown_offset)
+                        / self.dropdown_entry_height()) as usize;
+
+                    if let Some(id) = hidden.get(entry).and_then(|&i| self.tab_indices.get(i)) {
+                        shell.publish((self.on_select)(id.clone()));
+                    }
+                    event::Status::Captured
+                } else if button.contains(position) {
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if cursor.is_over(bounds) => {
+                let delta = match delta {
+                    ScrollDelta::Lines { y, .. } => y * self.dropdown_entry_height(),
+                    ScrollDelta::Pixels { y, .. } => *y,
+                };
+                #[allow(clippy::cast_precision_loss)]
+                let max_offset =
+                    (self.dropdown_entry_height() * hidden.len() as f32 - bounds.height).max(0.0);
+
+                state.dropdown_offset = (state.dropdown_offset - delta).clamp(0.0, max_offset);
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: KeyCode::Escape,
+                ..
+            }) => {
+                state.dropdown_open = false;
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    /// Draws the list of the hidden tabs.
+    pub(crate) fn draw_dropdown(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        bounds: Rectangle,
+        hidden: &[usize],
+        cursor: Cursor,
+    ) {
+        let appearance = theme.active(self.style, false);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: (0.0).into(),
+                border_width: appearance.tab_label_border_width,
+                border_color: appearance.tab_label_border_color,
+            },
+            appearance.tab_label_background,
+        );
+
+        let entry_height = self.dropdown_entry_height();
+        let offset = tree.state.downcast_ref::<State>().dropdown_offset;
+
+        // the entries are scrolled if the list does not fit into the window
+        renderer.with_layer(bounds, |renderer| {
+            for (row, &i) in hidden.iter().enumerate() {
+                let Some(label) = self.tab_labels.get(i) else {
+                    continue;
+                };
+
+                #[allow(clippy::cast_precision_loss)]
+                let entry = Rectangle {
+                    y: bounds.y + entry_height * row as f32 - offset,
+                    height: entry_height,
+                    ..bounds
+                };
+                if entry.y + entry.height < bounds.y || entry.y > bounds.y + bounds.height {
+                    continue;
+                }
+                let is_active = i == self.active_tab;
+                let appearance = if cursor.is_over(entry) {
+                    theme.hovered(self.style, is_active)
+                } else {
+                    theme.active(self.style, is_active)
+                };
+
+                if is_active || cursor.is_over(entry) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: entry,
+                            border_radius: (0.0).into(),
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        appearance.tab_label_background,
+                    );
+                }
+
+                if let TabLabel::Element(element) = label {
+                    let mut node = self.dropdown_element_node(renderer, element);
+                    node.move_to(Point::new(
+                        entry.x + self.padding,
+                        entry.center_y() - node.size().height / 2.0,
+                    ));
+                    element.as_widget().draw(
+                        &tree.children[i],
+                        renderer,
+                        theme,
+                        &renderer::Style {
+                            text_color: appearance.text_color,
+                        },
+                        Layout::new(&node),
+                        cursor,
+                        &entry,
+                    );
+                    continue;
+                }
+
+                let (icon, text) = dropdown_label(label);
+                let mut x = entry.x + self.padding;
+                if let Some(icon) = icon {
+                    renderer.fill_text(core::text::Text {
+                        content: &icon.to_string(),
+                        bounds: Rectangle {
+                            x,
+                            y: entry.center_y(),
+                            width: self.text_size * 1.3,
+                            height: entry.height,
+                        },
+                        size: self.text_size,
+                        color: appearance.icon_color,
+                        font: self.icon_font.unwrap_or(icons::ICON_FONT),
+                        horizontal_alignment: Horizontal::Left,
+                        vertical_alignment: Vertical::Center,
+                        line_height: LineHeight::Relative(1.3),
+                        shaping: iced_widget::text::Shaping::Advanced,
+                    });
+                    x += self.text_size * 1.3 + self.padding;
+                }
+
+                renderer.fill_text(core::text::Text {
+                    content: text,
+                    bounds: Rectangle {
+                        x,
+                        y: entry.center_y(),
+                        width: entry.x + entry.width - x,
+                        height: entry.height,
+                    },
+                    size: self.text_size,
+                    color: appearance.text_color,
+                    font: self.text_font.unwrap_or_default(),
+                    horizontal_alignment: Horizontal::Left,
+                    vertical_alignment: Vertical::Center,
+                    line_height: LineHeight::Relative(1.3),
+                    shaping: iced_widget::text::Shaping::Advanced,
+                });
+            }
+        });
+    }
 }
 
-impl<Message, TabId, Renderer> Widget<Message, Renderer> for TabBar<Message, TabId, Renderer>
+impl<Message, TabId, Renderer> Widget<Message, Renderer> for TabBar<'_, Message, TabId, Renderer>
 where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
     Renderer::Theme: StyleSheet + text::StyleSheet,
     TabId: Eq + Clone,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.tab_labels.iter().map(label_tree).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children_custom(
+            &self.tab_labels,
+            |tree, label| match label {
+                TabLabel::Element(element) => tree.diff(element.as_widget()),
+                _ => *tree = Tree::empty(),
+            },
+            label_tree,
+        );
+    }
+
     fn width(&self) -> Length {
         self.width
     }
@@ -329,132 +1053,211 @@ where
     }
 
     fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
-        self.tab_labels
-            .iter()
-            .fold(Row::<Message, Renderer>::new(), |row, tab_label| {
-                let label = match tab_label {
-                    TabLabel::Icon(icon) => Column::new().align_items(Alignment::Center).push(
-                        Text::new(icon.to_string())
-                            .size(self.icon_size)
-                            .font(self.icon_font.unwrap_or_default())
-                            .horizontal_alignment(alignment::Horizontal::Center)
-                            .vertical_alignment(alignment::Vertical::Center),
-                    ),
-                    TabLabel::Text(text) => Column::new().align_items(Alignment::Center).push(
-                        Text::new(text)
-                            .size(self.text_size)
-                            .font(self.text_font.unwrap_or_default())
-                            .horizontal_alignment(alignment::Horizontal::Center)
-                            .vertical_alignment(alignment::Vertical::Center),
-                    ),
-                    TabLabel::IconText(icon, text) => Column::new()
-                        .align_items(Alignment::Center)
-                        .push(
-                            Text::new(icon.to_string())
-                                .size(self.icon_size)
-                                .font(self.icon_font.unwrap_or_default())
-                                .horizontal_alignment(alignment::Horizontal::Center)
-                                .vertical_alignment(alignment::Vertical::Center),
-                        )
-                        .push(
-                            Text::new(text)
-                                .size(self.text_size)
-                                .width(self.tab_width)
-                                .font(self.text_font.unwrap_or_default()),
-                        ),
-                }
-                .width(self.tab_width)
-                .height(self.height);
-
-                let mut label_row = Row::new()
-                    .align_items(Alignment::Center)
-                    .padding(self.padding)
-                    .width(self.tab_width)
-                    .push(label);
-
-                if self.on_close.is_some() {
-                    label_row = label_row.push(
-                        Row::new()
-                            .width(Length::Fixed(self.close_size * 1.3 + 1.0))
-                            .height(Length::Fixed(self.close_size * 1.3 + 1.0))
-                            .align_items(Alignment::Center),
-                    );
-                }
+        if self.orientation == Orientation::Vertical {
+            return layout_vertical(self, renderer, limits);
+        }
 
-                row.push(label_row)
-            })
-            .width(self.width)
+        if self.overflow == Overflow::Shrink {
+            return Row::with_children(tabs(self, self.tab_width))
+                .width(self.width)
+                .height(self.height)
+                .spacing(self.spacing)
+                .layout(renderer, limits);
+        }
+
+        // the tabs keep their width and are scrolled if they don't fit
+        let tab_width = match self.tab_width {
+            Length::Fill | Length::FillPortion(_) => Length::Shrink,
+            width => width,
+        };
+        let content = Row::with_children(tabs(self, tab_width))
+            .width(Length::Shrink)
             .height(self.height)
             .spacing(self.spacing)
-            .layout(renderer, limits)
+            .layout(
+                renderer,
+                &layout::Limits::new(limits.min(), Size::new(f32::INFINITY, limits.max().height)),
+            );
+
+        let size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(content.size());
+
+        self.scrolled_node(size, &content)
     }
 
     fn on_event(
         &mut self,
-        _state: &mut Tree,
+        state: &mut Tree,
         event: Event,
         layout: Layout<'_>,
         cursor: Cursor,
-        _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) -> event::Status {
+        let Tree {
+            state, children, ..
+        } = state;
+        let state = state.downcast_mut::<State>();
+        let area = self.scroll_area(layout);
+
+        let offset = if let Some(area) = area {
+            self.scroll_into_view(state, layout, area);
+            state.offset
+        } else {
+            state.scrolled_to = None;
+            state.dropdown_open = false;
+            0.0
+        };
+        let content_cursor = content_cursor(cursor, area, offset, self.orientation);
+
+        // the elements of the labels only receive events to animate themselves
+        let mut messages = Vec::new();
+        let mut label_shell = Shell::new(&mut messages);
+        for ((label, tree), layout) in self
+            .tab_labels
+            .iter_mut()
+            .zip(children)
+            .zip(layout.children())
+        {
+            if let (TabLabel::Element(element), Some(element_layout)) = (
+                label,
+                layout
+                    .children()
+                    .next()
+                    .and_then(|label| label.children().next()),
+            ) {
+                let _ = element.as_widget_mut().on_event(
+                    tree,
+                    event.clone(),
+                    element_layout,
+                    content_cursor,
+                    renderer,
+                    clipboard,
+                    &mut label_shell,
+                    viewport,
+                );
+            }
+        }
+        if let Some(request) = label_shell.redraw_request() {
+            shell.request_redraw(request);
+        }
+        if label_shell.is_layout_invalid() {
+            shell.invalidate_layout();
+        }
+
+        if state.drag.is_some() {
+            let drag_cursor = cursor.position().map_or(Cursor::Unavailable, |position| {
+                Cursor::Available(position + self.orientation.vector(offset))
+            });
+            let status = self.on_drag(state, &event, layout, drag_cursor, shell);
+            if status == event::Status::Captured {
+                return status;
+            }
+        }
+
         match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if area.is_some() && cursor.is_over(layout.bounds()) =>
+            {
+                let (x, y) = match delta {
+                    ScrollDelta::Lines { x, y } => (x * SCROLL_LINE, y * SCROLL_LINE),
+                    ScrollDelta::Pixels { x, y } => (x, y),
+                };
+                let delta = if x.abs() > y.abs() { x } else { y };
+
+                if let Some(area) = area {
+                    state.offset = (state.offset - delta)
+                        .clamp(0.0, max_offset(layout, area, self.orientation));
+                }
+                event::Status::Captured
+            }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if layout
-                    .bounds()
-                    .contains(cursor.position().unwrap_or_default())
+                state.is_focused = cursor.is_over(layout.bounds());
+
+                if let Some(area) = area {
+                    if self.on_overflow_press(state, layout, area, cursor) {
+                        return event::Status::Captured;
+                    }
+                }
+
+                let Some(position) = content_cursor.position() else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(new_selected) = layout
+                    .children()
+                    .position(|layout| layout.bounds().contains(position))
                 {
-                    let tabs_map: Vec<bool> = layout
-                        .children()
-                        .map(|layout| {
-                            layout
-                                .bounds()
-                                .contains(cursor.position().unwrap_or_default())
-                        })
-                        .collect();
-
-                    if let Some(new_selected) = tabs_map.iter().position(|b| *b) {
-                        shell.publish(
-                            self.on_close
-                                .as_ref()
-                                .filter(|_on_close| {
-                                    let tab_layout = layout.children().nth(new_selected).expect("Native: Layout should have a tab layout at the selected index");
-                                    let cross_layout = tab_layout.children().nth(1).expect("Native: Layout should have a close layout");
-
-                                    cross_layout.bounds().contains(cursor.position().unwrap_or_default())
-                                })
-                                .map_or_else(
-                                    || (self.on_select)(self.tab_indices[new_selected].clone()),
-                                    |on_close| (on_close)(self.tab_indices[new_selected].clone()),
-                                ),
+                    let on_close = self.on_close.as_ref().filter(|_on_close| {
+                        let tab_layout = layout.children().nth(new_selected).expect(
+                            "Native: Layout should have a tab layout at the selected index",
                         );
-                        return event::Status::Captured;
+                        let cross_layout = tab_layout
+                            .children()
+                            .nth(1)
+                            .expect("Native: Layout should have a close layout");
+
+                        cross_layout.bounds().contains(position)
+                    });
+
+                    if on_close.is_none() && self.on_reorder.is_some() {
+                        state.drag = Some(Drag {
+                            from: new_selected,
+                            origin: position,
+                            cursor: position,
+                            is_dragging: false,
+                        });
                     }
+
+                    shell.publish(on_close.map_or_else(
+                        || (self.on_select)(self.tab_indices[new_selected].clone()),
+                        |on_close| (on_close)(self.tab_indices[new_selected].clone()),
+                    ));
+                    return event::Status::Captured;
                 }
                 event::Status::Ignored
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if state.is_focused => self.on_key_pressed(state, key_code, modifiers, shell),
             _ => event::Status::Ignored,
         }
     }
 
     fn mouse_interaction(
         &self,
-        _state: &Tree,
+        state: &Tree,
         layout: Layout<'_>,
         cursor: Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
+        let state = state.state.downcast_ref::<State>();
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let area = self.scroll_area(layout);
+        if let Some(area) = area {
+            let (start, end) = self.overflow_buttons(layout.bounds(), area);
+            if cursor.is_over(end) || start.is_some_and(|start| cursor.is_over(start)) {
+                return mouse::Interaction::Pointer;
+            }
+        }
+
+        let offset = area.map_or(0.0, |area| self.scroll_offset(state, layout, area));
+        let cursor = content_cursor(cursor, area, offset, self.orientation);
         let children = layout.children();
         let mut mouse_interaction = mouse::Interaction::default();
 
         for layout in children {
-            let is_mouse_over = layout
-                .bounds()
-                .contains(cursor.position().unwrap_or_default());
+            let is_mouse_over = cursor.is_over(layout.bounds());
             let new_mouse_interaction = if is_mouse_over {
                 mouse::Interaction::Pointer
             } else {
@@ -471,7 +1274,7 @@ where
 
     fn draw(
         &self,
-        _state: &Tree,
+        tree: &Tree,
         renderer: &mut Renderer,
         theme: &Renderer::Theme,
         _style: &renderer::Style,
@@ -480,7 +1283,6 @@ where
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
-        let children = layout.children();
         let is_mouse_over = bounds.contains(cursor.position().unwrap_or_default());
         let style_sheet = if is_mouse_over {
             theme.hovered(self.style, false)
@@ -500,20 +1302,490 @@ where
                 .unwrap_or_else(|| Color::TRANSPARENT.into()),
         );
 
-        for ((i, tab), layout) in self.tab_labels.iter().enumerate().zip(children) {
-            draw_tab(
-                renderer,
-                tab,
-                layout,
-                theme,
-                self.style,
-                i == self.get_active_tab_idx(),
-                cursor,
-                (self.icon_font.unwrap_or(icons::ICON_FONT), self.icon_size),
-                (self.text_font.unwrap_or_default(), self.text_size),
-                self.close_size,
-            );
+        let state = tree.state.downcast_ref::<State>();
+
+        let orientation = self.orientation;
+        if let Some(area) = self.scroll_area(layout) {
+            // the tabs are scrolled to an active tab set by the application
+            // before the next event arrives
+            let offset = self.scroll_offset(state, layout, area);
+            let content_cursor = content_cursor(cursor, Some(area), offset, orientation);
+
+            renderer.with_layer(area, |renderer| {
+                renderer.with_translation(orientation.vector(-offset), |renderer| {
+                    self.draw_tabs(
+                        tree,
+                        renderer,
+                        theme,
+                        layout,
+                        content_cursor,
+                        area + orientation.vector(offset),
+                    );
+                });
+            });
+
+            let (start, end) = self.overflow_buttons(bounds, area);
+            if let Some(start) = start {
+                let (previous, next) = match orientation {
+                    Orientation::Horizontal => {
+                        (icons::Icon::CaretLeftFill, icons::Icon::CaretRightFill)
+                    }
+                    Orientation::Vertical => (icons::Icon::CaretUpFill, icons::Icon::CaretDownFill),
+                };
+                draw_overflow_button(
+                    renderer,
+                    theme,
+                    self.style,
+                    start,
+                    previous,
+                    offset > 0.0,
+                    cursor,
+                );
+                draw_overflow_button(
+                    renderer,
+                    theme,
+                    self.style,
+                    end,
+                    next,
+                    offset < max_offset(layout, area, orientation),
+                    cursor,
+                );
+            } else {
+                draw_overflow_button(
+                    renderer,
+                    theme,
+                    self.style,
+                    end,
+                    icons::Icon::CaretDownFill,
+                    true,
+                    cursor,
+                );
+            }
+        } else {
+            // tabs of a vertical tab bar that don't fit are clipped
+            renderer.with_layer(bounds, |renderer| {
+                self.draw_tabs(tree, renderer, theme, layout, cursor, bounds);
+            });
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.focusable(tree.state.downcast_mut::<State>(), None);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let area = self.scroll_area(layout)?;
+        let (start, button) = self.overflow_buttons(layout.bounds(), area);
+        let state = tree.state.downcast_mut::<State>();
+
+        // the overlay is requested before the tab bar is drawn, so the hidden
+        // tabs are known once the active tab is scrolled into view
+        self.scroll_into_view(state, layout, area);
+
+        if !state.dropdown_open || start.is_some() {
+            return None;
+        }
+
+        let hidden = hidden_tabs(state, layout, area, self.orientation);
+        Some(TabBarOverlay::new(self, tree, button, hidden).overlay())
+    }
+}
+
+/// The state of a [`TabBar`](TabBar).
+#[derive(Debug, Default)]
+struct State {
+    /// The tab that is being dragged to reorder it.
+    drag: Option<Drag>,
+    /// The distance the tabs are scrolled by if they overflow the tab bar.
+    offset: f32,
+    /// The active tab the tabs were last scrolled to.
+    scrolled_to: Option<usize>,
+    /// Whether the list of the hidden tabs is open.
+    dropdown_open: bool,
+    /// The distance the list of the hidden tabs is scrolled by if it does not
+    /// fit into the window.
+    dropdown_offset: f32,
+    /// Whether the tab bar has keyboard focus.
+    is_focused: bool,
+}
+
+impl Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+/// A tab of a [`TabBar`](TabBar) that is being dragged to reorder it.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    /// The index of the dragged tab.
+    from: usize,
+    /// The position the drag started at.
+    origin: Point,
+    /// The last known position of the cursor.
+    cursor: Point,
+    /// Whether the cursor moved far enough to start reordering.
+    is_dragging: bool,
+}
+
+/// The index a dragged tab would be inserted at if dropped at `cursor`.
+fn drop_index(layout: Layout<'_>, cursor: Point, orientation: Orientation) -> usize {
+    layout
+        .children()
+        .filter(|tab| orientation.center(tab.bounds()) < orientation.coordinate(cursor))
+        .count()
+}
+
+/// The tab Ctrl+Tab or Ctrl+Shift+Tab cycles to from the active tab.
+fn cycled_tab(
+    key_code: KeyCode,
+    modifiers: keyboard::Modifiers,
+    active: usize,
+    count: usize,
+) -> Option<usize> {
+    if key_code != KeyCode::Tab || !modifiers.control() || count == 0 {
+        return None;
+    }
+
+    Some(if modifiers.shift() {
+        (active + count - 1) % count
+    } else {
+        (active + 1) % count
+    })
+}
+
+/// The tab the arrow keys along the tab bar, Home or End move to from the
+/// active tab, wrapping around at both ends.
+fn navigated_tab(
+    key_code: KeyCode,
+    orientation: Orientation,
+    active: usize,
+    count: usize,
+) -> Option<usize> {
+    let last = count.checked_sub(1)?;
+    let (previous, next) = match orientation {
+        Orientation::Horizontal => (KeyCode::Left, KeyCode::Right),
+        Orientation::Vertical => (KeyCode::Up, KeyCode::Down),
+    };
+
+    match key_code {
+        KeyCode::Home => Some(0),
+        KeyCode::End => Some(last),
+        key_code if key_code == previous => Some(if active == 0 { last } else { active - 1 }),
+        key_code if key_code == next => Some(if active >= last { 0 } else { active + 1 }),
+        _ => None,
+    }
+}
+
+/// The indices of the tabs that are not completely visible.
+fn hidden_tabs(
+    state: &State,
+    layout: Layout<'_>,
+    area: Rectangle,
+    orientation: Orientation,
+) -> Vec<usize> {
+    layout
+        .children()
+        .enumerate()
+        .filter(|(_, tab)| {
+            let tab = tab.bounds();
+            let start = orientation.start(tab) - orientation.start(area) - state.offset;
+            start < -0.5 || start + orientation.length(tab) > orientation.length(area) + 0.5
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Creates the tree of the element of a [`TabLabel`](TabLabel).
+fn label_tree<Renderer: core::Renderer>(label: &TabLabel<'_, Renderer>) -> Tree {
+    match label {
+        TabLabel::Element(element) => Tree::new(element.as_widget()),
+        _ => Tree::empty(),
+    }
+}
+
+/// The icon and the text of a tab in the list of the hidden tabs.
+///
+/// The element of a [`TabLabel::Element`](TabLabel::Element) is drawn instead.
+fn dropdown_label<'b, Renderer>(label: &'b TabLabel<'_, Renderer>) -> (Option<char>, &'b str) {
+    match label {
+        TabLabel::Icon(icon) => (Some(*icon), ""),
+        TabLabel::Text(text) => (None, text),
+        TabLabel::IconText(icon, text) => (Some(*icon), text),
+        TabLabel::Element(_) => (None, ""),
+    }
+}
+
+/// Returns true if tabs with the given total length don't fit into `length`.
+fn overflows(content_length: f32, length: f32) -> bool {
+    content_length - length > 0.5
+}
+
+/// The total length of the tabs along the tab bar.
+fn content_length(layout: Layout<'_>, orientation: Orientation) -> f32 {
+    let mut tabs = layout.children().map(|tab| tab.bounds());
+    let first = tabs.next().unwrap_or_default();
+    let last = tabs.last().unwrap_or(first);
+
+    orientation.start(last) + orientation.length(last) - orientation.start(first)
+}
+
+/// The distance the tabs can be scrolled by in the given area.
+fn max_offset(layout: Layout<'_>, area: Rectangle, orientation: Orientation) -> f32 {
+    (content_length(layout, orientation) - orientation.length(area)).max(0.0)
+}
+
+/// Moves the cursor over the area the tabs are scrolled in to the position
+/// of the tabs before they were scrolled.
+fn content_cursor(
+    cursor: Cursor,
+    area: Option<Rectangle>,
+    offset: f32,
+    orientation: Orientation,
+) -> Cursor {
+    match (cursor.position(), area) {
+        (Some(position), Some(area)) if area.contains(position) => {
+            Cursor::Available(position + orientation.vector(offset))
         }
+        (_, Some(_)) => Cursor::Unavailable,
+        (_, None) => cursor,
+    }
+}
+
+/// Draws a button scrolling the tabs or listing the hidden tabs.
+fn draw_overflow_button<Renderer>(
+    renderer: &mut Renderer,
+    theme: &Renderer::Theme,
+    style: <Renderer::Theme as StyleSheet>::Style,
+    bounds: Rectangle,
+    icon: icons::Icon,
+    is_enabled: bool,
+    cursor: Cursor,
+) where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    let appearance = if is_enabled && cursor.is_over(bounds) {
+        theme.hovered(style, false)
+    } else {
+        theme.active(style, false)
+    };
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: (0.0).into(),
+            border_width: appearance.tab_label_border_width,
+            border_color: appearance.tab_label_border_color,
+        },
+        appearance.tab_label_background,
+    );
+
+    let color = if is_enabled {
+        appearance.icon_color
+    } else {
+        Color {
+            a: appearance.icon_color.a * 0.3,
+            ..appearance.icon_color
+        }
+    };
+
+    renderer.fill_text(core::text::Text {
+        content: &icons::icon_to_char(icon).to_string(),
+        bounds: Rectangle {
+            x: bounds.center_x(),
+            y: bounds.center_y(),
+            ..bounds
+        },
+        size: DEFAULT_CLOSE_SIZE,
+        color,
+        font: icons::ICON_FONT,
+        horizontal_alignment: Horizontal::Center,
+        vertical_alignment: Vertical::Center,
+        line_height: LineHeight::Relative(1.3),
+        shaping: iced_widget::text::Shaping::Basic,
+    });
+}
+
+/// The element of a [`TabLabel`](TabLabel) laid out inside of the row of the tabs.
+///
+/// The element is drawn by the [`TabBar`](TabBar) with its own tree.
+struct LabelElement<'b, 'a, Renderer>(&'b Element<'a, (), Renderer>);
+
+impl<Message, Renderer> Widget<Message, Renderer> for LabelElement<'_, '_, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn width(&self) -> Length {
+        self.0.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.0.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.0.as_widget().layout(renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        _renderer: &mut Renderer,
+        _theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+/// Lays out the tabs of a vertical [`TabBar`](TabBar) from top to bottom.
+///
+/// The tabs fill the width of the widest tab or of the [`TabBar`](TabBar)
+/// if their width fills the available space. Tabs that don't fit are
+/// clipped if they aren't scrolled.
+fn layout_vertical<Message, TabId, Renderer>(
+    tab_bar: &TabBar<'_, Message, TabId, Renderer>,
+    renderer: &Renderer,
+    limits: &layout::Limits,
+) -> layout::Node
+where
+    Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + text::StyleSheet,
+    TabId: Eq + Clone,
+{
+    let content_limits =
+        layout::Limits::new(limits.min(), Size::new(limits.max().width, f32::INFINITY))
+            .width(tab_bar.width);
+    let fills = matches!(tab_bar.tab_width, Length::Fill | Length::FillPortion(_));
+    let tab_width = if fills {
+        Length::Shrink
+    } else {
+        tab_bar.tab_width
+    };
+
+    let mut content = Column::with_children(tabs(tab_bar, tab_width))
+        .width(Length::Shrink)
+        .spacing(tab_bar.spacing)
+        .layout(renderer, &content_limits);
+    let size = limits
+        .width(tab_bar.width)
+        .height(tab_bar.height)
+        .resolve(content.size());
+
+    if fills {
+        // the tabs are laid out again to fill the width of the tab bar
+        content = Column::with_children(tabs(tab_bar, tab_bar.tab_width))
+            .width(Length::Fixed(size.width))
+            .spacing(tab_bar.spacing)
+            .layout(renderer, &content_limits);
+    }
+
+    tab_bar.scrolled_node(size, &content)
+}
+
+/// Builds the tabs of a [`TabBar`](TabBar) with the given width of the tabs.
+fn tabs<'b, Message, TabId, Renderer>(
+    tab_bar: &'b TabBar<'_, Message, TabId, Renderer>,
+    tab_width: Length,
+) -> Vec<Element<'b, Message, Renderer>>
+where
+    Message: 'b,
+    Renderer: 'b + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet + text::StyleSheet,
+    TabId: Eq + Clone,
+{
+    let alignment = Alignment::from(tab_bar.label_alignment);
+
+    tab_bar
+        .tab_labels
+        .iter()
+        .map(|tab_label| {
+            let label = match tab_label {
+                TabLabel::Icon(icon) => Column::new().align_items(alignment).push(
+                    Text::new(icon.to_string())
+                        .size(tab_bar.icon_size)
+                        .font(tab_bar.icon_font.unwrap_or_default())
+                        .horizontal_alignment(alignment::Horizontal::Center)
+                        .vertical_alignment(alignment::Vertical::Center),
+                ),
+                TabLabel::Text(text) => Column::new().align_items(alignment).push(
+                    Text::new(text)
+                        .size(tab_bar.text_size)
+                        .font(tab_bar.text_font.unwrap_or_default())
+                        .horizontal_alignment(alignment::Horizontal::Center)
+                        .vertical_alignment(alignment::Vertical::Center),
+                ),
+                TabLabel::IconText(icon, text) => Column::new()
+                    .align_items(alignment)
+                    .push(
+                        Text::new(icon.to_string())
+                            .size(tab_bar.icon_size)
+                            .font(tab_bar.icon_font.unwrap_or_default())
+                            .horizontal_alignment(alignment::Horizontal::Center)
+                            .vertical_alignment(alignment::Vertical::Center),
+                    )
+                    .push(
+                        Text::new(text)
+                            .size(tab_bar.text_size)
+                            .width(tab_width)
+                            .font(tab_bar.text_font.unwrap_or_default()),
+                    ),
+                TabLabel::Element(element) => Column::new()
+                    .align_items(alignment)
+                    .push(Element::new(LabelElement(element))),
+            }
+            .width(tab_width)
+            .height(tab_bar.height);
+
+            let mut label_row = Row::new()
+                .align_items(Alignment::Center)
+                .padding(tab_bar.padding)
+                .width(tab_width)
+                .push(label);
+
+            if tab_bar.on_close.is_some() {
+                label_row = label_row.push(
+                    Row::new()
+                        .width(Length::Fixed(tab_bar.close_size * 1.3 + 1.0))
+                        .height(Length::Fixed(tab_bar.close_size * 1.3 + 1.0))
+                        .align_items(Alignment::Center),
+                );
+            }
+
+            label_row.into()
+        })
+        .collect()
+}
+
+/// The horizontal position text with the given alignment is drawn at
+/// inside of the bounds.
+fn aligned_x(bounds: Rectangle, alignment: Horizontal) -> f32 {
+    match alignment {
+        Horizontal::Left => bounds.x,
+        Horizontal::Center => bounds.center_x(),
+        Horizontal::Right => bounds.x + bounds.width,
     }
 }
 
@@ -525,7 +1797,8 @@ where
 )]
 fn draw_tab<Renderer>(
     renderer: &mut Renderer,
-    tab: &TabLabel,
+    tab: &TabLabel<'_, Renderer>,
+    tree: &Tree,
     layout: Layout<'_>,
     theme: &Renderer::Theme,
     style: <Renderer::Theme as StyleSheet>::Style,
@@ -534,9 +1807,10 @@ fn draw_tab<Renderer>(
     icon_data: (Font, f32),
     text_data: (Font, f32),
     close_size: f32,
+    alignment: Horizontal,
 ) where
     Renderer: core::Renderer + core::text::Renderer<Font = core::Font>,
-    Renderer::Theme: StyleSheet + text::StyleSheet,
+    Renderer::Theme: StyleSheet,
 {
     let is_mouse_over = layout
         .bounds()
@@ -574,14 +1848,14 @@ fn draw_tab<Renderer>(
             renderer.fill_text(core::text::Text {
                 content: &icon.to_string(),
                 bounds: Rectangle {
-                    x: icon_bounds.center_x(),
+                    x: aligned_x(icon_bounds, alignment),
                     y: icon_bounds.center_y(),
                     ..icon_bounds
                 },
                 size: icon_data.1,
                 color: style.icon_color,
                 font: icon_data.0,
-                horizontal_alignment: Horizontal::Center,
+                horizontal_alignment: alignment,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
                 shaping: iced_widget::text::Shaping::Advanced,
@@ -596,14 +1870,14 @@ fn draw_tab<Renderer>(
             renderer.fill_text(core::text::Text {
                 content: &text[..],
                 bounds: Rectangle {
-                    x: text_bounds.center_x(),
+                    x: aligned_x(text_bounds, alignment),
                     y: text_bounds.center_y(),
                     ..text_bounds
                 },
                 size: text_data.1,
                 color: style.text_color,
                 font: text_data.0,
-                horizontal_alignment: Horizontal::Center,
+                horizontal_alignment: alignment,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
                 shaping: iced_widget::text::Shaping::Advanced,
@@ -622,14 +1896,14 @@ fn draw_tab<Renderer>(
             renderer.fill_text(core::text::Text {
                 content: &icon.to_string(),
                 bounds: Rectangle {
-                    x: icon_bounds.center_x(),
+                    x: aligned_x(icon_bounds, alignment),
                     y: icon_bounds.center_y(),
                     ..icon_bounds
                 },
                 size: icon_data.1,
                 color: style.icon_color,
                 font: icon_data.0,
-                horizontal_alignment: Horizontal::Center,
+                horizontal_alignment: alignment,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
                 shaping: iced_widget::text::Shaping::Advanced,
@@ -638,19 +1912,36 @@ fn draw_tab<Renderer>(
             renderer.fill_text(core::text::Text {
                 content: &text[..],
                 bounds: Rectangle {
-                    x: text_bounds.center_x(),
+                    x: aligned_x(text_bounds, alignment),
                     y: text_bounds.center_y(),
                     ..text_bounds
                 },
                 size: text_data.1,
                 color: style.text_color,
                 font: text_data.0,
-                horizontal_alignment: Horizontal::Center,
+                horizontal_alignment: alignment,
                 vertical_alignment: Vertical::Center,
                 line_height: LineHeight::Relative(1.3),
                 shaping: iced_widget::text::Shaping::Advanced,
             });
         }
+        TabLabel::Element(element) => {
+            let element_layout = label_layout_children
+                .next()
+                .expect("Graphics: Layout should have an element layout for an Element");
+
+            element.as_widget().draw(
+                tree,
+                renderer,
+                theme,
+                &renderer::Style {
+                    text_color: style.text_color,
+                },
+                element_layout,
+                cursor,
+                &bounds,
+            );
+        }
     };
 
     if let Some(cross_layout) = children.next() {
@@ -675,7 +1966,7 @@ fn draw_tab<Renderer>(
     };
 }
 
-impl<'a, Message, TabId, Renderer> From<TabBar<Message, TabId, Renderer>>
+impl<'a, Message, TabId, Renderer> From<TabBar<'a, Message, TabId, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
@@ -683,7 +1974,193 @@ where
     Message: 'a,
     TabId: 'a + Eq + Clone,
 {
-    fn from(tab_bar: TabBar<Message, TabId, Renderer>) -> Self {
+    fn from(tab_bar: TabBar<'a, Message, TabId, Renderer>) -> Self {
         Element::new(tab_bar)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        content_cursor, cycled_tab, drop_index, max_offset, navigated_tab, Orientation, State,
+        TabBar, TabLabel,
+    };
+    use iced_widget::{
+        core::{
+            keyboard::{KeyCode, Modifiers},
+            layout::Node,
+            mouse::Cursor,
+            widget::Tree,
+            Layout, Point, Rectangle, Size, Widget,
+        },
+        Column, Space,
+    };
+
+    /// The layout of a tab bar of the given `size` with three tabs of 100x30
+    /// one after another along the `orientation`, starting at `offset`.
+    fn tabs_node(offset: f32, orientation: Orientation, size: Size) -> Node {
+        let tabs = (0..3)
+            .map(|i| {
+                let mut node = Node::new(Size::new(100.0, 30.0));
+                node.move_to(match orientation {
+                    Orientation::Horizontal => Point::new(offset + i as f32 * 100.0, 0.0),
+                    Orientation::Vertical => Point::new(0.0, offset + i as f32 * 30.0),
+                });
+                node
+            })
+            .collect();
+
+        Node::with_children(size, tabs)
+    }
+
+    #[test]
+    fn drop_index_is_the_number_of_tabs_left_of_the_cursor() {
+        let node = tabs_node(0.0, Orientation::Horizontal, Size::new(300.0, 30.0));
+        let layout = Layout::new(&node);
+
+        assert_eq!(
+            drop_index(layout, Point::new(-10.0, 15.0), Orientation::Horizontal),
+            0
+        );
+        assert_eq!(
+            drop_index(layout, Point::new(40.0, 15.0), Orientation::Horizontal),
+            0
+        );
+        assert_eq!(
+            drop_index(layout, Point::new(60.0, 15.0), Orientation::Horizontal),
+            1
+        );
+        assert_eq!(
+            drop_index(layout, Point::new(160.0, 15.0), Orientation::Horizontal),
+            2
+        );
+        assert_eq!(
+            drop_index(layout, Point::new(400.0, 15.0), Orientation::Horizontal),
+            3
+        );
+    }
+
+    #[test]
+    fn cursor_over_scrolled_tabs_is_moved_by_the_offset() {
+        let node = tabs_node(24.0, Orientation::Horizontal, Size::new(200.0, 30.0));
+        let layout = Layout::new(&node);
+        let area = Rectangle::new(Point::new(24.0, 0.0), Size::new(152.0, 30.0));
+
+        assert!((max_offset(layout, area, Orientation::Horizontal) - 148.0).abs() < f32::EPSILON);
+
+        let cursor = Cursor::Available(Point::new(50.0, 15.0));
+        assert_eq!(
+            content_cursor(cursor, Some(area), 100.0, Orientation::Horizontal).position(),
+            Some(Point::new(150.0, 15.0))
+        );
+        assert_eq!(
+            content_cursor(cursor, None, 100.0, Orientation::Horizontal),
+            cursor
+        );
+
+        // the cursor over the scroll buttons is not over any tab
+        let cursor = Cursor::Available(Point::new(10.0, 15.0));
+        assert_eq!(
+            content_cursor(cursor, Some(area), 100.0, Orientation::Horizontal),
+            Cursor::Unavailable
+        );
+    }
+
+    #[test]
+    fn active_tab_set_by_the_application_is_scrolled_into_view() {
+        let node = tabs_node(24.0, Orientation::Horizontal, Size::new(200.0, 30.0));
+        let layout = Layout::new(&node);
+        let area = Rectangle::new(Point::new(24.0, 0.0), Size::new(152.0, 30.0));
+
+        let tab_bar = (0..3)
+            .fold(TabBar::<(), usize>::new(|_| ()), |tab_bar, i| {
+                tab_bar.push(i, TabLabel::Text(i.to_string()))
+            })
+            .set_active_tab(&2);
+        let mut state = State::default();
+
+        // drawing scrolls to the new active tab before the next event
+        assert!((tab_bar.scroll_offset(&state, layout, area) - 148.0).abs() < f32::EPSILON);
+        assert!(state.offset.abs() < f32::EPSILON);
+
+        tab_bar.scroll_into_view(&mut state, layout, area);
+        assert!((state.offset - 148.0).abs() < f32::EPSILON);
+
+        // the tabs can be scrolled away from the active tab afterwards
+        state.offset = 0.0;
+        assert!(tab_bar.scroll_offset(&state, layout, area).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn only_element_labels_have_a_tree() {
+        let tab_bar = TabBar::<(), usize>::new(|_| ())
+            .push(0, TabLabel::Text(String::from("text")))
+            .push(
+                1,
+                TabLabel::Element(Column::new().push(Space::with_width(10)).into()),
+            )
+            .push(2, TabLabel::Icon('x'));
+
+        let mut tree = Tree::new(&tab_bar as &dyn Widget<(), crate::Renderer>);
+        assert_eq!(tree.children.len(), 3);
+        assert!(tree.children[0].children.is_empty());
+        assert_eq!(tree.children[1].children.len(), 1);
+        assert!(tree.children[2].children.is_empty());
+
+        // the trees follow the labels when tabs are removed
+        let tab_bar = TabBar::<(), usize>::new(|_| ()).push(
+            1,
+            TabLabel::Element(Column::new().push(Space::with_width(10)).into()),
+        );
+        Widget::<(), crate::Renderer>::diff(&tab_bar, &mut tree);
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].children.len(), 1);
+    }
+
+    #[test]
+    fn vertical_tabs_are_scrolled_and_dropped_along_the_y_axis() {
+        let node = tabs_node(24.0, Orientation::Vertical, Size::new(100.0, 80.0));
+        let layout = Layout::new(&node);
+        let area = Rectangle::new(Point::new(0.0, 24.0), Size::new(100.0, 32.0));
+
+        assert!((max_offset(layout, area, Orientation::Vertical) - 58.0).abs() < f32::EPSILON);
+        assert_eq!(
+            drop_index(layout, Point::new(50.0, 60.0), Orientation::Vertical),
+            1
+        );
+
+        let cursor = Cursor::Available(Point::new(50.0, 30.0));
+        assert_eq!(
+            content_cursor(cursor, Some(area), 20.0, Orientation::Vertical).position(),
+            Some(Point::new(50.0, 50.0))
+        );
+    }
+
+    #[test]
+    fn ctrl_tab_cycles_through_the_tabs() {
+        assert_eq!(cycled_tab(KeyCode::Tab, Modifiers::CTRL, 1, 3), Some(2));
+        assert_eq!(cycled_tab(KeyCode::Tab, Modifiers::CTRL, 2, 3), Some(0));
+        assert_eq!(
+            cycled_tab(KeyCode::Tab, Modifiers::CTRL | Modifiers::SHIFT, 0, 3),
+            Some(2)
+        );
+        assert_eq!(cycled_tab(KeyCode::Tab, Modifiers::empty(), 0, 3), None);
+        assert_eq!(cycled_tab(KeyCode::Tab, Modifiers::CTRL, 0, 0), None);
+    }
+
+    #[test]
+    fn arrow_keys_along_the_tab_bar_select_the_neighboring_tabs() {
+        let horizontal = Orientation::Horizontal;
+        assert_eq!(navigated_tab(KeyCode::Right, horizontal, 0, 3), Some(1));
+        assert_eq!(navigated_tab(KeyCode::Right, horizontal, 2, 3), Some(0));
+        assert_eq!(navigated_tab(KeyCode::Left, horizontal, 0, 3), Some(2));
+        assert_eq!(navigated_tab(KeyCode::Down, horizontal, 0, 3), None);
+        assert_eq!(navigated_tab(KeyCode::End, horizontal, 0, 3), Some(2));
+
+        let vertical = Orientation::Vertical;
+        assert_eq!(navigated_tab(KeyCode::Down, vertical, 0, 3), Some(1));
+        assert_eq!(navigated_tab(KeyCode::Up, vertical, 1, 3), Some(0));
+        assert_eq!(navigated_tab(KeyCode::Right, vertical, 0, 3), None);
+        assert_eq!(navigated_tab(KeyCode::Home, vertical, 2, 0), None);
+    }
+}
@@ -0,0 +1,75 @@
+//! An [`Orientation`](Orientation) for defining the direction the tabs of a
+//! [`TabBar`](super::TabBar) are laid out in.
+//!
+//! *This API requires the following crate features to be activated: `tab_bar`*
+
+use iced_widget::core::{Point, Rectangle, Vector};
+
+/// The direction the tabs of a [`TabBar`](super::TabBar) are laid out in.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Orientation {
+    /// The tabs are laid out from left to right and Left and Right select the
+    /// neighboring tabs.
+    #[default]
+    Horizontal,
+
+    /// The tabs are laid out from top to bottom and Up and Down select the
+    /// neighboring tabs.
+    Vertical,
+}
+
+impl Orientation {
+    /// The start of the bounds along the tabs.
+    pub(crate) fn start(self, bounds: Rectangle) -> f32 {
+        match self {
+            Self::Horizontal => bounds.x,
+            Self::Vertical => bounds.y,
+        }
+    }
+
+    /// The length of the bounds along the tabs.
+    pub(crate) fn length(self, bounds: Rectangle) -> f32 {
+        match self {
+            Self::Horizontal => bounds.width,
+            Self::Vertical => bounds.height,
+        }
+    }
+
+    /// The center of the bounds along the tabs.
+    pub(crate) fn center(self, bounds: Rectangle) -> f32 {
+        self.start(bounds) + self.length(bounds) / 2.0
+    }
+
+    /// The coordinate of the point along the tabs.
+    pub(crate) fn coordinate(self, point: Point) -> f32 {
+        match self {
+            Self::Horizontal => point.x,
+            Self::Vertical => point.y,
+        }
+    }
+
+    /// A vector moving by `distance` along the tabs.
+    pub(crate) fn vector(self, distance: f32) -> Vector {
+        match self {
+            Self::Horizontal => Vector::new(distance, 0.0),
+            Self::Vertical => Vector::new(0.0, distance),
+        }
+    }
+
+    /// The part of the bounds starting at `start` with the given `length`
+    /// along the tabs.
+    pub(crate) fn slice(self, bounds: Rectangle, start: f32, length: f32) -> Rectangle {
+        match self {
+            Self::Horizontal => Rectangle {
+                x: start,
+                width: length,
+                ..bounds
+            },
+            Self::Vertical => Rectangle {
+                y: start,
+                height: length,
+                ..bounds
+            },
+        }
+    }
+}
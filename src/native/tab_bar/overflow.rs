@@ -0,0 +1,24 @@
+//! An [`Overflow`](Overflow) for defining how a [`TabBar`](super::TabBar)
+//! shows tabs that don't fit into its width.
+//!
+//! *This API requires the following crate features to be activated: `tab_bar`*
+
+/// How a [`TabBar`](super::TabBar) shows tabs that don't fit into its width.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum Overflow {
+    /// The tabs are shrunk until they fit into the [`TabBar`](super::TabBar).
+    ///
+    /// The tabs of a vertical [`TabBar`](super::TabBar) can't shrink and are
+    /// clipped instead.
+    #[default]
+    Shrink,
+
+    /// The tabs keep their size and are scrolled with the arrow buttons at
+    /// both ends of the [`TabBar`](super::TabBar) or the mouse wheel.
+    Scroll,
+
+    /// The tabs keep their size and are scrolled with the mouse wheel. A button
+    /// at the end of the [`TabBar`](super::TabBar) opens a list of the tabs
+    /// that are hidden.
+    Dropdown,
+}
@@ -1,12 +1,13 @@
-//! A [`TabLabel`](TabLabel) showing an icon and/or a text on a tab.
+//! A [`TabLabel`](TabLabel) showing an icon, a text or any element on a tab.
 //!
 //! *This API requires the following crate features to be activated: `tab_bar`*
 
-/// A [`TabLabel`](TabLabel) showing an icon and/or a text on a tab
+use iced_widget::core::Element;
+
+/// A [`TabLabel`](TabLabel) showing an icon, a text or any element on a tab
 /// on a [`TabBar`](super::TabBar).
 #[allow(missing_debug_implementations)]
-#[derive(Clone, Hash)]
-pub enum TabLabel {
+pub enum TabLabel<'a, Renderer = crate::Renderer> {
     /// A [`TabLabel`](TabLabel) showing only an icon on the tab.
     Icon(char),
 
@@ -15,5 +16,12 @@ pub enum TabLabel {
 
     /// A [`TabLabel`](TabLabel) showing an icon and a text on the tab.
     IconText(char, String),
-    // TODO: Support any element as a label.
+
+    /// A [`TabLabel`](TabLabel) showing any element on the tab, like a text
+    /// with a badge or a spinner.
+    ///
+    /// The element is only displayed and receives events to animate itself,
+    /// the messages it produces are ignored. It is drawn with the text color of
+    /// the tab.
+    Element(Element<'a, (), Renderer>),
 }
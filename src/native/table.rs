@@ -0,0 +1,912 @@
+//! Display tabular data with a header row, sortable and resizable columns.
+//!
+//! *This API requires the following crate features to be activated: `table`*
+use iced_widget::{
+    core::{
+        self,
+        alignment::{Horizontal, Vertical},
+        event,
+        layout::{Limits, Node},
+        mouse::{self, Cursor},
+        overlay, renderer, text, touch,
+        widget::{
+            tree::{State, Tag},
+            Operation, Tree,
+        },
+        Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+    },
+    text::LineHeight,
+};
+
+use crate::graphics::icons::{Icon, ICON_FONT};
+pub use crate::style::table::{Appearance, StyleSheet, TableStyles};
+
+/// The default padding of the cells of a [`Table`](Table).
+const DEFAULT_PADDING: f32 = 5.0;
+/// The width of the area around a column divider that can be grabbed.
+const DIVIDER_GRAB_WIDTH: f32 = 6.0;
+/// The default minimum width of a [`TableColumn`](TableColumn).
+const DEFAULT_MIN_WIDTH: f32 = 20.0;
+
+/// A table displaying rows of elements below a header row.
+///
+/// The columns can be sorted by clicking on their header and resized by
+/// dragging the divider on the right side of their header. Both only emit
+/// messages, sorting the rows and storing the widths is left to the application.
+///
+/// # Example
+/// ```ignore
+/// # use iced::widget::Text;
+/// # use iced_aw::table::{SortOrder, Table, TableColumn};
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sort(usize, SortOrder),
+///     Resize(usize, f32),
+///     Select(usize),
+/// }
+///
+/// let table = Table::new(vec![
+///         TableColumn::new("Name", 150.0).sortable(true),
+///         TableColumn::new("Age", 80.0).sortable(true),
+///     ])
+///     .push_row(vec![Text::new("Alice").into(), Text::new("32").into()])
+///     .push_row(vec![Text::new("Bob").into(), Text::new("27").into()])
+///     .on_sort(Message::Sort)
+///     .on_resize(Message::Resize)
+///     .on_select(Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// The columns of the [`Table`](Table).
+    columns: Vec<TableColumn>,
+    /// The rows of cells of the [`Table`](Table).
+    rows: Vec<Vec<Element<'a, Message, Renderer>>>,
+    /// The column and order the [`Table`](Table) is currently sorted by.
+    sort: Option<(usize, SortOrder)>,
+    /// The currently selected row of the [`Table`](Table).
+    selected: Option<usize>,
+    /// The message that is send when a sortable header is clicked.
+    on_sort: Option<Box<dyn Fn(usize, SortOrder) -> Message + 'a>>,
+    /// The message that is send when a column divider is dragged.
+    on_resize: Option<Box<dyn Fn(usize, f32) -> Message + 'a>>,
+    /// The message that is send when a row is clicked.
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    /// The width of the [`Table`](Table).
+    width: Length,
+    /// The padding of the cells of the [`Table`](Table).
+    padding: f32,
+    /// The text size of the header row of the [`Table`](Table).
+    text_size: Option<f32>,
+    /// The font of the header row of the [`Table`](Table).
+    font: Renderer::Font,
+    /// The style of the [`Table`](Table).
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+/// A column of a [`Table`](Table).
+#[derive(Clone, Debug)]
+pub struct TableColumn {
+    /// The label of the header of the column.
+    header: String,
+    /// The width of the column.
+    width: f32,
+    /// The minimum width the column can be resized to.
+    min_width: f32,
+    /// Whether the column can be sorted by clicking on its header.
+    sortable: bool,
+    /// Whether the column can be resized by dragging its divider.
+    resizable: bool,
+}
+
+impl TableColumn {
+    /// Creates a new [`TableColumn`](TableColumn) with the given header and width.
+    pub fn new(header: impl Into<String>, width: f32) -> Self {
+        Self {
+            header: header.into(),
+            width,
+            min_width: DEFAULT_MIN_WIDTH,
+            sortable: false,
+            resizable: true,
+        }
+    }
+
+    /// Sets the minimum width the [`TableColumn`](TableColumn) can be resized to.
+    #[must_use]
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Sets whether the [`TableColumn`](TableColumn) can be sorted.
+    #[must_use]
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Sets whether the [`TableColumn`](TableColumn) can be resized.
+    #[must_use]
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+/// The order a [`Table`](Table) is sorted in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Sort from the smallest to the largest value.
+    Ascending,
+    /// Sort from the largest to the smallest value.
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the opposite [`SortOrder`](SortOrder).
+    #[must_use]
+    pub const fn toggle(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`Table`](Table) without any rows.
+    ///
+    /// It expects:
+    ///     - The [`TableColumn`](TableColumn)s of the header row.
+    #[must_use]
+    pub fn new(columns: Vec<TableColumn>) -> Self {
+        Self::with_rows(columns, Vec::new())
+    }
+
+    /// Creates a new [`Table`](Table) with the given rows.
+    ///
+    /// It expects:
+    ///     - The [`TableColumn`](TableColumn)s of the header row.
+    ///     - The rows of cells, one [`Element`](Element) per column.
+    #[must_use]
+    pub fn with_rows(
+        columns: Vec<TableColumn>,
+        rows: Vec<Vec<Element<'a, Message, Renderer>>>,
+    ) -> Self {
+        Self {
+            columns,
+            rows,
+            sort: None,
+            selected: None,
+            on_sort: None,
+            on_resize: None,
+            on_select: None,
+            width: Length::Shrink,
+            padding: DEFAULT_PADDING,
+            text_size: None,
+            font: Renderer::Font::default(),
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Adds a row of cells to the [`Table`](Table).
+    #[must_use]
+    pub fn push_row(mut self, row: Vec<Element<'a, Message, Renderer>>) -> Self {
+        self.insert_row(row);
+        self
+    }
+
+    /// Inserts a row of cells into the [`Table`](Table).
+    pub fn insert_row(&mut self, row: Vec<Element<'a, Message, Renderer>>) {
+        self.rows.push(row);
+    }
+
+    /// Sets the column and the order the [`Table`](Table) is currently sorted by.
+    /// This only displays the sort indicator, the rows need to be sorted by the application.
+    #[must_use]
+    pub fn sort(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the currently selected row of the [`Table`](Table).
+    #[must_use]
+    pub fn selected(mut self, row: Option<usize>) -> Self {
+        self.selected = row;
+        self
+    }
+
+    /// Sets the message that is send when the header of a sortable column is clicked.
+    /// It receives the index of the column and the requested [`SortOrder`](SortOrder).
+    #[must_use]
+    pub fn on_sort<F>(mut self, on_sort: F) -> Self
+    where
+        F: 'a + Fn(usize, SortOrder) -> Message,
+    {
+        self.on_sort = Some(Box::new(on_sort));
+        self
+    }
+
+    /// Sets the message that is send when the divider of a resizable column is dragged.
+    /// It receives the index of the column and its new width.
+    #[must_use]
+    pub fn on_resize<F>(mut self, on_resize: F) -> Self
+    where
+        F: 'a + Fn(usize, f32) -> Message,
+    {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+
+    /// Sets the message that is send when a row is clicked.
+    /// It receives the index of the row.
+    #[must_use]
+    pub fn on_select<F>(mut self, on_select: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    /// Sets the width of the [`Table`](Table).
+    #[must_use]
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the cells of the [`Table`](Table).
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the text size of the header row of the [`Table`](Table).
+    #[must_use]
+    pub fn text_size(mut self, size: f32) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the header row of the [`Table`](Table).
+    #[must_use]
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`Table`](Table).
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Returns the height of the header row.
+    fn header_height(&self, renderer: &Renderer) -> f32 {
+        let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+        LineHeight::default().to_absolute(size.into()).0 + 2.0 * self.padding
+    }
+
+    /// Returns the bounds of the header cell of each column.
+    fn header_cells(&self, bounds: Rectangle, header_height: f32) -> Vec<Rectangle> {
+        self.columns
+            .iter()
+            .scan(bounds.x, |x, column| {
+                let cell = Rectangle {
+                    x: *x,
+                    y: bounds.y,
+                    width: column.width,
+                    height: header_height,
+                };
+                *x += column.width;
+                Some(cell)
+            })
+            .collect()
+    }
+
+    /// Returns the index of the resizable column whose divider is at the given position.
+    fn divider_at(&self, position: Point, header_cells: &[Rectangle]) -> Option<usize> {
+        header_cells
+            .iter()
+            .zip(&self.columns)
+            .position(|(cell, column)| {
+                column.resizable
+                    && (position.x - (cell.x + cell.width)).abs() <= DIVIDER_GRAB_WIDTH / 2.0
+                    && position.y >= cell.y
+                    && position.y <= cell.y + cell.height
+            })
+            .filter(|_| self.on_resize.is_some())
+    }
+
+    /// Returns the index of the sortable column whose header is at the given position.
+    fn sortable_header_at(&self, position: Point, header_cells: &[Rectangle]) -> Option<usize> {
+        header_cells
+            .iter()
+            .zip(&self.columns)
+            .position(|(cell, column)| column.sortable && cell.contains(position))
+            .filter(|_| self.on_sort.is_some())
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Table<'_, Message, Renderer>
+where
+    Renderer: core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<TableState>()
+    }
+
+    fn state(&self) -> State {
+        State::new(TableState::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.rows.iter().flatten().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.rows.iter().flatten().collect::<Vec<_>>());
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let header_height = self.header_height(renderer);
+        let table_width: f32 = self.columns.iter().map(|column| column.width).sum();
+
+        let mut y = header_height;
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut x = 0.0;
+                let mut row_height: f32 = 0.0;
+                let cells: Vec<Node> = row
+                    .iter()
+                    .enumerate()
+                    .map(|(index, cell)| {
+                        // cells without a column are laid out without any space
+                        let width = self.columns.get(index).map_or(0.0, |column| column.width);
+                        let cell_limits = Limits::new(
+                            Size::ZERO,
+                            Size::new((width - 2.0 * self.padding).max(0.0), limits.max().height),
+                        );
+                        let mut node = cell.as_widget().layout(renderer, &cell_limits);
+                        node.move_to(Point::new(x + self.padding, self.padding));
+                        x += width;
+                        row_height = row_height.max(node.size().height);
+                        node
+                    })
+                    .collect();
+
+                row_height += 2.0 * self.padding;
+                let mut node = Node::with_children(Size::new(table_width, row_height), cells);
+                node.move_to(Point::new(0.0, y));
+                y += row_height;
+                node
+            })
+            .collect();
+
+        let size = limits.width(self.width).resolve(Size::new(table_width, y));
+
+        Node::with_children(Size::new(size.width, y), rows)
+    }
+
+    fn on_event(
+        &mut self,
+        state: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let children_status = self
+            .rows
+            .iter_mut()
+            .flatten()
+            .zip(&mut state.children)
+            .zip(layout.children().flat_map(Layout::children))
+            .map(|((cell, state), layout)| {
+                cell.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if children_status == event::Status::Captured {
+            return children_status;
+        }
+
+        let table_state: &mut TableState = state.state.downcast_mut();
+        let bounds = layout.bounds();
+        let header_cells = self.header_cells(bounds, self.header_height(renderer));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(bounds) else {
+                    return event::Status::Ignored;
+                };
+
+                if let Some(column) = self.divider_at(position, &header_cells) {
+                    table_state.dragging = Some(Dragging {
+                        column,
+                        origin: position.x,
+                        width: self.columns[column].width,
+                    });
+                    return event::Status::Captured;
+                }
+
+                if let Some((column, on_sort)) = self
+                    .sortable_header_at(position, &header_cells)
+                    .zip(self.on_sort.as_ref())
+                {
+                    let order = match self.sort {
+                        Some((sorted, order)) if sorted == column => order.toggle(),
+                        _ => SortOrder::Ascending,
+                    };
+                    shell.publish(on_sort(column, order));
+                    return event::Status::Captured;
+                }
+
+                if let Some(on_select) = &self.on_select {
+                    if let Some(row) = row_at(layout, position) {
+                        shell.publish(on_select(row));
+                        return event::Status::Captured;
+                    }
+                }
+            }
+
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some((dragging, on_resize)) =
+                    table_state.dragging.zip(self.on_resize.as_ref())
+                {
+                    let width = (dragging.width + position.x - dragging.origin)
+                        .max(self.columns[dragging.column].min_width);
+                    shell.publish(on_resize(dragging.column, width));
+                    return event::Status::Captured;
+                }
+            }
+
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. })
+                if table_state.dragging.is_some() =>
+            {
+                table_state.dragging = None;
+                return event::Status::Captured;
+            }
+
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        state: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let table_state: &TableState = state.state.downcast_ref();
+
+        if table_state.dragging.is_some() {
+            return mouse::Interaction::ResizingHorizontally;
+        }
+
+        let children_interaction = self
+            .rows
+            .iter()
+            .flatten()
+            .zip(&state.children)
+            .zip(layout.children().flat_map(Layout::children))
+            .map(|((cell, state), layout)| {
+                cell.as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .fold(mouse::Interaction::default(), mouse::Interaction::max);
+
+        let Some(position) = cursor.position_over(layout.bounds()) else {
+            return children_interaction;
+        };
+        let header_cells = self.header_cells(layout.bounds(), self.header_height(renderer));
+
+        let interaction = if self.divider_at(position, &header_cells).is_some() {
+            mouse::Interaction::ResizingHorizontally
+        } else if self.sortable_header_at(position, &header_cells).is_some()
+            || (self.on_select.is_some() && row_at(layout, position).is_some())
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        };
+
+        children_interaction.max(interaction)
+    }
+
+    fn draw(
+        &self,
+        state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let table_state: &TableState = state.state.downcast_ref();
+        let bounds = layout.bounds();
+        let appearance = theme.active(self.style);
+        let header_height = self.header_height(renderer);
+        let header_cells = self.header_cells(bounds, header_height);
+        let text_size = self.text_size.unwrap_or_else(|| renderer.default_size());
+
+        // Background
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        // Rows
+        let hovered_row = cursor.position_over(bounds).and_then(|position| {
+            layout
+                .children()
+                .position(|row| row.bounds().contains(position))
+        });
+
+        // the trees of the cells of all rows follow each other
+        let mut offset = 0;
+        for (index, (row, row_layout)) in self.rows.iter().zip(layout.children()).enumerate() {
+            let background = if self.selected == Some(index) {
+                Some(appearance.selected_row_background)
+            } else if hovered_row == Some(index) && self.on_select.is_some() {
+                appearance.hovered_row_background
+            } else if index % 2 == 1 {
+                appearance.striped_row_background
+            } else {
+                appearance.row_background
+            };
+
+            if let Some(background) = background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_layout.bounds(),
+                        border_radius: 0.0.into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            let style = renderer::Style {
+                text_color: if self.selected == Some(index) {
+                    appearance.selected_text_color
+                } else {
+                    appearance.text_color
+                },
+            };
+
+            let cells = offset..offset + row.len();
+            offset = cells.end;
+            for ((cell, state), cell_layout) in row
+                .iter()
+                .zip(&state.children[cells])
+                .zip(row_layout.children())
+            {
+                cell.as_widget().draw(
+                    state,
+                    renderer,
+                    theme,
+                    &style,
+                    cell_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+
+        // Header
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    height: header_height,
+                    ..bounds
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.header_background,
+        );
+
+        let hovered_divider = cursor
+            .position_over(bounds)
+            .and_then(|position| self.divider_at(position, &header_cells));
+
+        for (index, (cell, column)) in header_cells.iter().zip(&self.columns).enumerate() {
+            let indicator = match self.sort {
+                Some((sorted, SortOrder::Ascending)) if sorted == index => Some(Icon::CaretUpFill),
+                Some((sorted, SortOrder::Descending)) if sorted == index => {
+                    Some(Icon::CaretDownFill)
+                }
+                _ => None,
+            };
+            let indicator_width = if indicator.is_some() { text_size } else { 0.0 };
+            let text_width = (cell.width - 2.0 * self.padding - indicator_width).max(0.0);
+
+            // the header is clipped to its column when the column is narrower
+            renderer.with_layer(
+                Rectangle {
+                    x: cell.x + self.padding,
+                    width: text_width,
+                    ..*cell
+                },
+                |renderer| {
+                    renderer.fill_text(text::Text {
+                        content: &column.header,
+                        bounds: Rectangle {
+                            x: cell.x + self.padding,
+                            y: cell.center_y(),
+                            width: text_width,
+                            height: cell.height,
+                        },
+                        size: text_size,
+                        line_height: LineHeight::default(),
+                        color: appearance.header_text_color,
+                        font: self.font,
+                        horizontal_alignment: Horizontal::Left,
+                        vertical_alignment: Vertical::Center,
+                        shaping: text::Shaping::Advanced,
+                    });
+                },
+            );
+
+            if let Some(icon) = indicator {
+                renderer.fill_text(text::Text {
+                    content: &char::from(icon).to_string(),
+                    bounds: Rectangle {
+                        x: cell.x + cell.width - self.padding - indicator_width / 2.0,
+                        y: cell.center_y(),
+                        width: indicator_width,
+                        height: cell.height,
+                    },
+                    size: text_size,
+                    line_height: LineHeight::default(),
+                    color: appearance.sort_indicator_color,
+                    font: ICON_FONT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    shaping: text::Shaping::Basic,
+                });
+            }
+
+            let highlighted = hovered_divider == Some(index)
+                || table_state
+                    .dragging
+                    .is_some_and(|dragging| dragging.column == index);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: cell.x + cell.width - 1.0,
+                        y: bounds.y,
+                        width: 1.0,
+                        height: if highlighted {
+                            bounds.height
+                        } else {
+                            header_height
+                        },
+                    },
+                    border_radius: 0.0.into(),
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                if highlighted {
+                    appearance.divider_hovered_color
+                } else {
+                    appearance.divider_color
+                },
+            );
+        }
+
+        // Header separator
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    y: bounds.y + header_height - 1.0,
+                    height: 1.0,
+                    ..bounds
+                },
+                border_radius: 0.0.into(),
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            appearance.divider_color,
+        );
+
+        // Border
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: 0.0.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
+    fn operate(
+        &self,
+        state: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        for ((cell, state), layout) in self
+            .rows
+            .iter()
+            .flatten()
+            .zip(&mut state.children)
+            .zip(layout.children().flat_map(Layout::children))
+        {
+            cell.as_widget().operate(state, layout, renderer, operation);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        state: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let children = self
+            .rows
+            .iter_mut()
+            .flatten()
+            .zip(&mut state.children)
+            .zip(layout.children().flat_map(Layout::children))
+            .filter_map(|((cell, state), layout)| {
+                cell.as_widget_mut().overlay(state, layout, renderer)
+            })
+            .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(table: Table<'a, Message, Renderer>) -> Self {
+        Element::new(table)
+    }
+}
+
+/// Returns the index of the row at the given position.
+fn row_at(layout: Layout<'_>, position: Point) -> Option<usize> {
+    layout
+        .children()
+        .position(|row| row.bounds().contains(position))
+}
+
+/// The state of a [`Table`](Table).
+#[derive(Clone, Copy, Debug, Default)]
+struct TableState {
+    /// The column divider that is currently dragged by the user.
+    dragging: Option<Dragging>,
+}
+
+/// A column divider of a [`Table`](Table) that is dragged.
+#[derive(Clone, Copy, Debug)]
+struct Dragging {
+    /// The index of the column that is resized.
+    column: usize,
+    /// The horizontal position the drag started at.
+    origin: f32,
+    /// The width of the column when the drag started.
+    width: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{row_at, Table, TableColumn};
+    use iced_widget::core::{layout::Node, Layout, Point, Rectangle, Size};
+
+    fn table() -> Table<'static, ()> {
+        Table::new(vec![
+            TableColumn::new("Name", 100.0).sortable(true),
+            TableColumn::new("Size", 50.0).resizable(false),
+            TableColumn::new("Date", 80.0),
+        ])
+    }
+
+    #[test]
+    fn headers_are_hit_only_with_their_handlers() {
+        let table = table();
+        let cells =
+            table.header_cells(Rectangle::new(Point::ORIGIN, Size::new(230.0, 100.0)), 20.0);
+        let divider = Point::new(101.0, 10.0);
+        let header = Point::new(50.0, 10.0);
+
+        assert_eq!(table.divider_at(divider, &cells), None);
+        assert_eq!(table.sortable_header_at(header, &cells), None);
+
+        let table = table.on_sort(|_, _| ()).on_resize(|_, _| ());
+
+        assert_eq!(table.divider_at(divider, &cells), Some(0));
+        assert_eq!(table.sortable_header_at(header, &cells), Some(0));
+        // the divider of a column that is not resizable
+        assert_eq!(table.divider_at(Point::new(150.0, 10.0), &cells), None);
+        // the header of a column that is not sortable
+        assert_eq!(
+            table.sortable_header_at(Point::new(120.0, 10.0), &cells),
+            None
+        );
+        // below the header row
+        assert_eq!(table.divider_at(Point::new(101.0, 30.0), &cells), None);
+        assert_eq!(
+            table.sortable_header_at(Point::new(50.0, 30.0), &cells),
+            None
+        );
+    }
+
+    #[test]
+    fn rows_are_hit_by_their_bounds() {
+        let rows = (0..2)
+            .map(|i| {
+                let mut node = Node::new(Size::new(230.0, 20.0));
+                node.move_to(Point::new(0.0, 20.0 + i as f32 * 20.0));
+                node
+            })
+            .collect();
+        let node = Node::with_children(Size::new(230.0, 60.0), rows);
+        let layout = Layout::new(&node);
+
+        assert_eq!(row_at(layout, Point::new(10.0, 10.0)), None);
+        assert_eq!(row_at(layout, Point::new(10.0, 30.0)), Some(0));
+        assert_eq!(row_at(layout, Point::new(10.0, 50.0)), Some(1));
+    }
+}
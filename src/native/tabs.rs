@@ -8,18 +8,24 @@
 
 use iced_widget::{
     core::{
-        self, event,
+        self,
+        alignment::Horizontal,
+        event, keyboard,
         layout::{Limits, Node},
         mouse::{self, Cursor},
-        renderer,
-        widget::{Operation, Tree},
+        renderer, touch,
+        widget::{tree, Operation, Tree},
         Clipboard, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
     },
     runtime::Font,
     text, Row,
 };
 
-use crate::{native::tab_bar::TabBar, style::tab_bar::StyleSheet, TabLabel};
+use crate::{
+    native::tab_bar::{Orientation, Overflow, TabBar},
+    style::tab_bar::StyleSheet,
+    TabLabel,
+};
 
 pub mod tab_bar_position;
 pub use tab_bar_position::TabBarPosition;
@@ -27,6 +33,10 @@ pub use tab_bar_position::TabBarPosition;
 /// A [`Tabs`](Tabs) widget for showing a [`TabBar`](super::tab_bar::TabBar)
 /// along with the tab's content.
 ///
+/// After the [`Tabs`](Tabs) were clicked, Ctrl+Tab and Ctrl+Shift+Tab cycle
+/// through the tabs and Ctrl+W closes the active tab if
+/// [`on_close`](Tabs::on_close) is set, even while the content is focused.
+///
 /// # Example
 /// ```ignore
 /// # use iced_aw::{TabLabel, tabs::Tabs};
@@ -59,7 +69,7 @@ where
     TabId: Eq + Clone,
 {
     /// The [`TabBar`](crate::native::TabBar) of the [`Tabs`](Tabs).
-    tab_bar: TabBar<Message, TabId, Renderer>,
+    tab_bar: TabBar<'a, Message, TabId, Renderer>,
     /// The vector containing the content of the tabs.
     tabs: Vec<Element<'a, Message, Renderer>>,
     /// The vector containing the indices of the tabs.
@@ -103,7 +113,11 @@ where
     ///     * the function that will be called if a tab is selected by the user.
     ///         It takes the index of the selected tab.
     pub fn with_tabs<F>(
-        tabs: Vec<(TabId, TabLabel, Element<'a, Message, Renderer>)>,
+        tabs: Vec<(
+            TabId,
+            TabLabel<'a, Renderer>,
+            Element<'a, Message, Renderer>,
+        )>,
         on_select: F,
     ) -> Self
     where
@@ -142,6 +156,20 @@ where
         self
     }
 
+    /// Sets the message that will be produced when a tab of the
+    /// [`TabBar`](super::tab_bar::TabBar) is dragged to a new position.
+    ///
+    /// Setting this allows reordering the tabs by dragging them. The order of
+    /// the tabs is kept by the application.
+    #[must_use]
+    pub fn on_reorder<F>(mut self, on_reorder: F) -> Self
+    where
+        F: 'static + Fn(TabId, usize) -> Message,
+    {
+        self.tab_bar = self.tab_bar.on_reorder(on_reorder);
+        self
+    }
+
     /// Sets the width of the [`Tabs`](Tabs).
     #[must_use]
     pub fn width(mut self, width: Length) -> Self {
@@ -180,6 +208,14 @@ where
         self
     }
 
+    /// Sets how the [`TabBar`](super::tab_bar::TabBar) of the [`Tabs`](Tabs)
+    /// shows tabs that don't fit into its width.
+    #[must_use]
+    pub fn tab_bar_overflow(mut self, overflow: Overflow) -> Self {
+        self.tab_bar = self.tab_bar.overflow(overflow);
+        self
+    }
+
     /// Sets the icon size of the [`TabLabel`](super::tab_bar::TabLabel) of the
     /// [`TabBar`](super::tab_bar::TabBar).
     #[must_use]
@@ -247,16 +283,31 @@ where
 
     /// Sets the [`TabBarPosition`](TabBarPosition) of the
     /// [`TabBar`](super::tab_bar::TabBar).
+    ///
+    /// A [`TabBar`](super::tab_bar::TabBar) on the left or the right of the
+    /// content lays out its tabs vertically.
     #[must_use]
     pub fn tab_bar_position(mut self, position: TabBarPosition) -> Self {
+        self.tab_bar = self.tab_bar.orientation(match position {
+            TabBarPosition::Top | TabBarPosition::Bottom => Orientation::Horizontal,
+            TabBarPosition::Left | TabBarPosition::Right => Orientation::Vertical,
+        });
         self.tab_bar_position = position;
         self
     }
 
+    /// Sets the horizontal alignment of the labels inside of the tabs of the
+    /// [`TabBar`](super::tab_bar::TabBar).
+    #[must_use]
+    pub fn tab_label_alignment(mut self, alignment: Horizontal) -> Self {
+        self.tab_bar = self.tab_bar.label_alignment(alignment);
+        self
+    }
+
     /// Pushes a [`TabLabel`](super::tab_bar::TabLabel) along with the tabs
     /// content to the [`Tabs`](Tabs).
     #[must_use]
-    pub fn push<E>(mut self, id: TabId, tab_label: TabLabel, element: E) -> Self
+    pub fn push<E>(mut self, id: TabId, tab_label: TabLabel<'a, Renderer>, element: E) -> Self
     where
         E: Into<Element<'a, Message, Renderer>>,
     {
@@ -272,6 +323,14 @@ where
         self.tab_bar = self.tab_bar.set_active_tab(id);
         self
     }
+
+    /// The widgets of the [`TabBar`](super::tab_bar::TabBar) and the content
+    /// of the tabs, whose trees are the children of the tree of the [`Tabs`](Tabs).
+    fn widgets(&self) -> Vec<&dyn Widget<Message, Renderer>> {
+        std::iter::once(&self.tab_bar as &dyn Widget<Message, Renderer>)
+            .chain(self.tabs.iter().map(Element::as_widget))
+            .collect()
+    }
 }
 
 impl<'a, Message, TabId, Renderer> Widget<Message, Renderer> for Tabs<'a, Message, TabId, Renderer>
@@ -280,12 +339,20 @@ where
     Renderer::Theme: StyleSheet + text::StyleSheet,
     TabId: Eq + Clone,
 {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
     fn children(&self) -> Vec<Tree> {
-        self.tabs.iter().map(Tree::new).collect()
+        self.widgets().into_iter().map(Tree::new).collect()
     }
 
     fn diff(&self, tree: &mut Tree) {
-        tree.diff_children(&self.tabs);
+        tree.diff_children(&self.widgets());
     }
 
     fn width(&self) -> Length {
@@ -297,11 +364,28 @@ where
     }
 
     fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
-        let tab_bar_limits = limits.width(self.width).height(Length::Shrink);
+        let is_vertical = matches!(
+            self.tab_bar_position,
+            TabBarPosition::Left | TabBarPosition::Right
+        );
+        let limits = limits.width(self.width);
 
+        // a vertical tab bar is as high as the content, a horizontal one as high as its tabs
+        let tab_bar_limits = if is_vertical {
+            limits.height(self.height)
+        } else {
+            limits.height(Length::Shrink)
+        };
         let mut tab_bar_node = self.tab_bar.layout(renderer, &tab_bar_limits);
-
-        let tab_content_limits = limits.width(self.width).height(self.height);
+        let tab_bar_size = tab_bar_node.size();
+
+        let tab_content_limits = if is_vertical {
+            limits
+                .height(self.height)
+                .shrink(Size::new(tab_bar_size.width, 0.0))
+        } else {
+            limits.height(self.height)
+        };
 
         let mut tab_content_node = self
             .tabs
@@ -315,33 +399,42 @@ where
                 },
                 |element| element.as_widget().layout(renderer, &tab_content_limits),
             );
+        let tab_content_size = tab_content_node.size();
 
-        tab_bar_node.move_to(Point::new(
-            tab_bar_node.bounds().x,
-            tab_bar_node.bounds().y
-                + match self.tab_bar_position {
-                    TabBarPosition::Top => 0.0,
-                    TabBarPosition::Bottom => tab_content_node.bounds().height,
-                },
-        ));
-
-        tab_content_node.move_to(Point::new(
-            tab_content_node.bounds().x,
-            tab_content_node.bounds().y
-                + match self.tab_bar_position {
-                    TabBarPosition::Top => tab_bar_node.bounds().height,
-                    TabBarPosition::Bottom => 0.0,
-                },
-        ));
+        match self.tab_bar_position {
+            TabBarPosition::Top => {
+                tab_content_node.move_to(Point::new(0.0, tab_bar_size.height));
+            }
+            TabBarPosition::Bottom => {
+                tab_bar_node.move_to(Point::new(0.0, tab_content_size.height));
+            }
+            TabBarPosition::Left => {
+                tab_content_node.move_to(Point::new(tab_bar_size.width, 0.0));
+            }
+            TabBarPosition::Right => {
+                tab_bar_node.move_to(Point::new(tab_content_size.width, 0.0));
+            }
+        }
 
-        Node::with_children(
+        let size = if is_vertical {
             Size::new(
-                tab_content_node.size().width,
-                tab_bar_node.size().height + tab_content_node.size().height,
-            ),
+                tab_bar_size.width + tab_content_size.width,
+                tab_bar_size.height.max(tab_content_size.height),
+            )
+        } else {
+            Size::new(
+                tab_content_size.width,
+                tab_bar_size.height + tab_content_size.height,
+            )
+        };
+
+        Node::with_children(
+            size,
             match self.tab_bar_position {
-                TabBarPosition::Top => vec![tab_bar_node, tab_content_node],
-                TabBarPosition::Bottom => vec![tab_content_node, tab_bar_node],
+                TabBarPosition::Top | TabBarPosition::Left => vec![tab_bar_node, tab_content_node],
+                TabBarPosition::Bottom | TabBarPosition::Right => {
+                    vec![tab_content_node, tab_bar_node]
+                }
             },
         )
     }
@@ -357,9 +450,28 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) -> event::Status {
+        // the shortcuts of the tab bar also work while the content is focused
+        let tabs_state = state.state.downcast_mut::<State>();
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                tabs_state.is_focused = cursor.is_over(layout.bounds());
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if tabs_state.is_focused
+                && self.tab_bar.on_shortcut(key_code, modifiers, shell)
+                    == event::Status::Captured =>
+            {
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
         let mut children = layout.children();
         let (tab_bar_layout, tab_content_layout) = match self.tab_bar_position {
-            TabBarPosition::Top => {
+            TabBarPosition::Top | TabBarPosition::Left => {
                 let tab_bar_layout = children
                     .next()
                     .expect("Native: Layout should have a TabBar layout at top position");
@@ -368,7 +480,7 @@ where
                     .expect("Native: Layout should have a tab content layout at top position");
                 (tab_bar_layout, tab_content_layout)
             }
-            TabBarPosition::Bottom => {
+            TabBarPosition::Bottom | TabBarPosition::Right => {
                 let tab_content_layout = children
                     .next()
                     .expect("Native: Layout should have a tab content layout at bottom position");
@@ -380,7 +492,7 @@ where
         };
 
         let status_tab_bar = self.tab_bar.on_event(
-            &mut Tree::empty(),
+            &mut state.children[0],
             event.clone(),
             tab_bar_layout,
             cursor,
@@ -395,7 +507,7 @@ where
             .get_mut(idx)
             .map_or(event::Status::Ignored, |element| {
                 element.as_widget_mut().on_event(
-                    &mut state.children[idx],
+                    &mut state.children[idx + 1],
                     event,
                     tab_content_layout,
                     cursor,
@@ -420,17 +532,17 @@ where
         // Tab bar
         let mut children = layout.children();
         let tab_bar_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .next()
                 .expect("Native: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .last()
                 .expect("Native: There should be a TabBar at the bottom position"),
         };
 
         let mut mouse_interaction = mouse::Interaction::default();
         let new_mouse_interaction = self.tab_bar.mouse_interaction(
-            &Tree::empty(),
+            &state.children[0],
             tab_bar_layout,
             cursor,
             viewport,
@@ -444,17 +556,17 @@ where
         // Tab content
         let mut children = layout.children();
         let tab_content_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .last()
                 .expect("Graphics: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .next()
                 .expect("Graphics: There should be a TabBar at the bottom position"),
         };
         let idx = self.tab_bar.get_active_tab_idx();
         if let Some(element) = self.tabs.get(idx) {
             let new_mouse_interaction = element.as_widget().mouse_interaction(
-                &state.children[idx],
+                &state.children[idx + 1],
                 tab_content_layout,
                 cursor,
                 viewport,
@@ -481,16 +593,16 @@ where
     ) {
         let mut children = layout.children();
         let tab_bar_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .next()
                 .expect("Native: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .last()
                 .expect("Native: There should be a TabBar at the bottom position"),
         };
 
         self.tab_bar.draw(
-            &Tree::empty(),
+            &state.children[0],
             renderer,
             theme,
             style,
@@ -502,10 +614,10 @@ where
         let mut children = layout.children();
 
         let tab_content_layout = match self.tab_bar_position {
-            TabBarPosition::Top => children
+            TabBarPosition::Top | TabBarPosition::Left => children
                 .last()
                 .expect("Graphics: There should be a TabBar at the top position"),
-            TabBarPosition::Bottom => children
+            TabBarPosition::Bottom | TabBarPosition::Right => children
                 .next()
                 .expect("Graphics: There should be a TabBar at the bottom position"),
         };
@@ -513,7 +625,7 @@ where
         let idx = self.tab_bar.get_active_tab_idx();
         if let Some(element) = self.tabs.get(idx) {
             element.as_widget().draw(
-                &state.children[idx],
+                &state.children[idx + 1],
                 renderer,
                 theme,
                 style,
@@ -530,17 +642,31 @@ where
         layout: Layout<'_>,
         renderer: &Renderer,
     ) -> Option<core::overlay::Element<'b, Message, Renderer>> {
-        let layout = match self.tab_bar_position {
-            TabBarPosition::Top => layout.children().nth(1),
-            TabBarPosition::Bottom => layout.children().next(),
+        let (tab_bar_layout, layout) = match self.tab_bar_position {
+            TabBarPosition::Top | TabBarPosition::Left => {
+                (layout.children().next(), layout.children().nth(1))
+            }
+            TabBarPosition::Bottom | TabBarPosition::Right => {
+                (layout.children().nth(1), layout.children().next())
+            }
         };
+        let (tab_bar_state, tab_states) = state.children.split_at_mut(1);
+        let idx = self.tab_bar.get_active_tab_idx();
+
+        // the list of the hidden tabs of the tab bar
+        let tab_bar_overlay = tab_bar_layout.and_then(|tab_bar_layout| {
+            self.tab_bar
+                .overlay(&mut tab_bar_state[0], tab_bar_layout, renderer)
+        });
+        if tab_bar_overlay.is_some() {
+            return tab_bar_overlay;
+        }
 
         layout.and_then(|layout| {
-            let idx = self.tab_bar.get_active_tab_idx();
             self.tabs
                 .get_mut(idx)
                 .map(Element::as_widget_mut)
-                .and_then(|w| w.overlay(&mut state.children[idx], layout, renderer))
+                .and_then(|w| w.overlay(&mut tab_states[idx], layout, renderer))
         })
     }
 
@@ -552,20 +678,42 @@ where
         operation: &mut dyn Operation<Message>,
     ) {
         let active_tab = self.tab_bar.get_active_tab_idx();
+        let (tab_bar_layout, tab_content_layout) = match self.tab_bar_position {
+            TabBarPosition::Top | TabBarPosition::Left => {
+                (layout.children().next(), layout.children().nth(1))
+            }
+            TabBarPosition::Bottom | TabBarPosition::Right => {
+                (layout.children().nth(1), layout.children().next())
+            }
+        };
+        let tab_bar_layout = tab_bar_layout.expect("Native: Layout should have a TabBar layout");
+        let tab_content_layout =
+            tab_content_layout.expect("Native: Layout should have a tab content layout");
+
         operation.container(None, layout.bounds(), &mut |operation| {
-            self.tabs[active_tab].as_widget().operate(
-                &mut tree.children[active_tab],
-                layout
-                    .children()
-                    .nth(1)
-                    .expect("TabBar is 0th child, contents are 1st node"),
-                renderer,
-                operation,
-            );
+            self.tab_bar
+                .operate(&mut tree.children[0], tab_bar_layout, renderer, operation);
+
+            if let Some(element) = self.tabs.get(active_tab) {
+                element.as_widget().operate(
+                    &mut tree.children[active_tab + 1],
+                    tab_content_layout,
+                    renderer,
+                    operation,
+                );
+            }
         });
     }
 }
 
+/// The state of a [`Tabs`](Tabs) widget.
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the [`Tabs`](Tabs) were clicked last, which enables the
+    /// shortcuts of the [`TabBar`](super::tab_bar::TabBar).
+    is_focused: bool,
+}
+
 impl<'a, Message, TabId, Renderer> From<Tabs<'a, Message, TabId, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -15,4 +15,12 @@ pub enum TabBarPosition {
     /// A [`TabBarPosition`] for placing the
     /// [`TabBar`](crate::native::tab_bar::TabBar) on bottom of its content.
     Bottom,
+
+    /// A [`TabBarPosition`] for placing a vertical
+    /// [`TabBar`](crate::native::tab_bar::TabBar) on the left of its content.
+    Left,
+
+    /// A [`TabBarPosition`] for placing a vertical
+    /// [`TabBar`](crate::native::tab_bar::TabBar) on the right of its content.
+    Right,
 }
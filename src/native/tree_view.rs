@@ -0,0 +1,973 @@
+//! Display hierarchical data as a tree of expandable nodes.
+//!
+//! *This API requires the following crate features to be activated: `tree_view`*
+use iced_widget::core::{
+    self,
+    alignment::{Horizontal, Vertical},
+    event,
+    keyboard::{self, KeyCode},
+    layout::{Limits, Node},
+    mouse::{self, Cursor},
+    overlay, renderer,
+    text::LineHeight,
+    touch,
+    widget::{
+        tree::{State as TreeState, Tag},
+        Operation, Tree,
+    },
+    Clipboard, Color, Element, Event, Layout, Length, Point, Rectangle, Shell, Size, Widget,
+};
+
+use crate::graphics::icons::{Icon, ICON_FONT};
+use crate::style::tree_view::StyleSheet;
+
+/// A node of a [`TreeView`].
+///
+/// A node is identified by an `Id` chosen by the application, which is passed
+/// back in the messages of the [`TreeView`]. Whether a node is expanded is
+/// owned by the application as well, so children can be loaded lazily when
+/// the expand message is received.
+#[allow(missing_debug_implementations)]
+pub struct TreeNode<'a, Id, Message, Renderer = crate::Renderer> {
+    /// The identifier of the node.
+    id: Id,
+    /// The content of the node.
+    content: Element<'a, Message, Renderer>,
+    /// The child nodes.
+    children: Vec<Self>,
+    /// Whether the children are shown.
+    expanded: bool,
+    /// Whether the node can be expanded although no children were added yet.
+    has_children: bool,
+}
+
+impl<'a, Id, Message, Renderer> TreeNode<'a, Id, Message, Renderer> {
+    /// Creates a new collapsed [`TreeNode`] without children.
+    pub fn new(id: Id, content: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            id,
+            content: content.into(),
+            children: Vec::new(),
+            expanded: false,
+            has_children: false,
+        }
+    }
+
+    /// Creates a new collapsed [`TreeNode`] with the given children.
+    pub fn with_children(
+        id: Id,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        children: Vec<Self>,
+    ) -> Self {
+        Self {
+            children,
+            ..Self::new(id, content)
+        }
+    }
+
+    /// Adds a child to the [`TreeNode`].
+    #[must_use]
+    pub fn push(mut self, child: Self) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Sets whether the children of the [`TreeNode`] are shown.
+    #[must_use]
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Marks the [`TreeNode`] as expandable even though its children are not
+    /// loaded yet.
+    ///
+    /// Use this together with [`TreeView::on_expand`] to load the children
+    /// only when the node is expanded.
+    #[must_use]
+    pub fn has_children(mut self, has_children: bool) -> Self {
+        self.has_children = has_children;
+        self
+    }
+
+    /// Returns true if the [`TreeNode`] can be expanded.
+    fn is_expandable(&self) -> bool {
+        self.has_children || !self.children.is_empty()
+    }
+}
+
+/// A visible node of the tree in display order.
+struct Row<'b, 'a, Id, Message, Renderer> {
+    /// The node of the row.
+    node: &'b TreeNode<'a, Id, Message, Renderer>,
+    /// The nesting level of the node.
+    depth: usize,
+    /// The row of the parent node.
+    parent: Option<usize>,
+}
+
+/// Flattens the expanded parts of the given nodes into rows.
+fn flatten<'b, 'a, Id, Message, Renderer>(
+    nodes: &'b [TreeNode<'a, Id, Message, Renderer>],
+) -> Vec<Row<'b, 'a, Id, Message, Renderer>> {
+    /// Inner flattening function
+    fn rec<'b, 'a, Id, Message, Renderer>(
+        nodes: &'b [TreeNode<'a, Id, Message, Renderer>],
+        depth: usize,
+        parent: Option<usize>,
+        rows: &mut Vec<Row<'b, 'a, Id, Message, Renderer>>,
+    ) {
+        for node in nodes {
+            let index = rows.len();
+            rows.push(Row {
+                node,
+                depth,
+                parent,
+            });
+
+            if node.expanded {
+                rec(&node.children, depth + 1, Some(index), rows);
+            }
+        }
+    }
+
+    let mut rows = Vec::new();
+    rec(nodes, 0, None, &mut rows);
+    rows
+}
+
+/// Creates the tree of a node.
+///
+/// The trees mirror the hierarchy of the nodes, so the state of a content
+/// stays with its node when other nodes are expanded or collapsed. The tree
+/// of a node holds the tree of its content and a tree holding the trees of
+/// its children.
+fn node_tree<Id, Message, Renderer>(node: &TreeNode<'_, Id, Message, Renderer>) -> Tree
+where
+    Renderer: core::Renderer,
+{
+    Tree {
+        tag: Tag::stateless(),
+        state: TreeState::None,
+        children: vec![
+            Tree::new(&node.content),
+            Tree {
+                tag: Tag::stateless(),
+                state: TreeState::None,
+                children: node.children.iter().map(node_tree).collect(),
+            },
+        ],
+    }
+}
+
+/// Diffs the trees of the given nodes with the nodes, following the hierarchy
+/// of the nodes.
+fn diff_nodes<Id, Message, Renderer>(
+    trees: &mut Vec<Tree>,
+    nodes: &[TreeNode<'_, Id, Message, Renderer>],
+) where
+    Renderer: core::Renderer,
+{
+    trees.truncate(nodes.len());
+
+    for (tree, node) in trees.iter_mut().zip(nodes) {
+        tree.children[0].diff(&node.content);
+        diff_nodes(&mut tree.children[1].children, &node.children);
+    }
+
+    let existing = trees.len();
+    trees.extend(nodes[existing..].iter().map(node_tree));
+}
+
+/// Collects the trees of the contents of the visible nodes in the same order
+/// as [`flatten`].
+fn content_trees<'t, Id, Message, Renderer>(
+    nodes: &[TreeNode<'_, Id, Message, Renderer>],
+    trees: &'t [Tree],
+    collected: &mut Vec<&'t Tree>,
+) {
+    for (node, tree) in nodes.iter().zip(trees) {
+        collected.push(&tree.children[0]);
+
+        if node.expanded {
+            content_trees(&node.children, &tree.children[1].children, collected);
+        }
+    }
+}
+
+/// Collects the mutable trees of the contents of the visible nodes in the
+/// same order as [`flatten`].
+fn content_trees_mut<'t, Id, Message, Renderer>(
+    nodes: &[TreeNode<'_, Id, Message, Renderer>],
+    trees: &'t mut [Tree],
+    collected: &mut Vec<&'t mut Tree>,
+) {
+    for (node, tree) in nodes.iter().zip(trees) {
+        let [content_tree, children_tree] = &mut tree.children[..] else {
+            continue;
+        };
+        collected.push(content_tree);
+
+        if node.expanded {
+            content_trees_mut(&node.children, &mut children_tree.children, collected);
+        }
+    }
+}
+
+/// Collects the contents of the visible nodes and their trees in the same
+/// order as [`flatten`].
+fn contents_mut<'b, 'a, 't, Id, Message, Renderer>(
+    nodes: &'b mut [TreeNode<'a, Id, Message, Renderer>],
+    trees: &'t mut [Tree],
+    contents: &mut Vec<(&'b mut Element<'a, Message, Renderer>, &'t mut Tree)>,
+) {
+    for (
+        TreeNode {
+            content,
+            children,
+            expanded,
+            ..
+        },
+        tree,
+    ) in nodes.iter_mut().zip(trees)
+    {
+        let [content_tree, children_tree] = &mut tree.children[..] else {
+            continue;
+        };
+        contents.push((content, content_tree));
+
+        if *expanded {
+            contents_mut(children, &mut children_tree.children, contents);
+        }
+    }
+}
+
+/// A widget displaying hierarchical data as a tree of expandable nodes.
+///
+/// Clicking the arrow in front of a node or pressing Right and Left while
+/// the tree view is focused expands and collapses it, clicking a node or
+/// moving to it with Up and Down selects it.
+///
+/// # Example
+///
+/// ```ignore
+/// # use iced_aw::tree_view::{TreeNode, TreeView};
+/// # use iced_widget::text;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Expand(usize),
+///     Collapse(usize),
+///     Select(usize),
+/// }
+///
+/// let tree_view = TreeView::new(vec![TreeNode::with_children(
+///     0,
+///     text("src"),
+///     vec![TreeNode::new(1, text("main.rs"))],
+/// )
+/// .expanded(true)])
+/// .on_expand(Message::Expand)
+/// .on_collapse(Message::Collapse)
+/// .on_select(Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, Id, Message, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// The top level nodes.
+    nodes: Vec<TreeNode<'a, Id, Message, Renderer>>,
+    /// The selected node.
+    selected: Option<Id>,
+    /// The function producing the message when a node is expanded.
+    on_expand: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    /// The function producing the message when a node is collapsed.
+    on_collapse: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    /// The function producing the message when a node is selected.
+    on_select: Option<Box<dyn Fn(Id) -> Message + 'a>>,
+    /// The width of one indentation level.
+    indent: f32,
+    /// The vertical padding of the nodes.
+    padding: f32,
+    /// The width of the tree view.
+    width: Length,
+    /// The height of the tree view.
+    height: Length,
+    /// The style of the tree view.
+    style: <Renderer::Theme as StyleSheet>::Style,
+}
+
+impl<'a, Id, Message, Renderer> TreeView<'a, Id, Message, Renderer>
+where
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Creates a new [`TreeView`] with the given top level nodes.
+    #[must_use]
+    pub fn new(nodes: Vec<TreeNode<'a, Id, Message, Renderer>>) -> Self {
+        Self {
+            nodes,
+            selected: None,
+            on_expand: None,
+            on_collapse: None,
+            on_select: None,
+            indent: 20.0,
+            padding: 2.0,
+            width: Length::Fill,
+            height: Length::Shrink,
+            style: <Renderer::Theme as StyleSheet>::Style::default(),
+        }
+    }
+
+    /// Sets the selected node of the [`TreeView`].
+    #[must_use]
+    pub fn selected(mut self, selected: Option<Id>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the message produced when a node is expanded.
+    #[must_use]
+    pub fn on_expand<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Id) -> Message,
+    {
+        self.on_expand = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message produced when a node is collapsed.
+    #[must_use]
+    pub fn on_collapse<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Id) -> Message,
+    {
+        self.on_collapse = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message produced when a node is selected.
+    #[must_use]
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(Id) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of one indentation level of the [`TreeView`].
+    #[must_use]
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the vertical padding of the nodes of the [`TreeView`].
+    #[must_use]
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    #[must_use]
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`TreeView`].
+    #[must_use]
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    #[must_use]
+    pub fn style(mut self, style: <Renderer::Theme as StyleSheet>::Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The state of a [`TreeView`].
+#[derive(Debug, Default)]
+pub struct State {
+    /// Whether the tree view has keyboard focus.
+    is_focused: bool,
+    /// The row moved to with the keyboard or clicked last.
+    cursor: Option<usize>,
+}
+
+impl<Id, Message, Renderer> TreeView<'_, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq,
+    Renderer: core::Renderer,
+    Renderer::Theme: StyleSheet,
+{
+    /// Returns the row with keyboard focus.
+    ///
+    /// The selected node takes precedence over the last row moved to, so a
+    /// selection changed by the application is picked up.
+    fn cursor(&self, state: &State, rows: &[Row<'_, '_, Id, Message, Renderer>]) -> Option<usize> {
+        self.selected
+            .as_ref()
+            .and_then(|selected| rows.iter().position(|row| row.node.id == *selected))
+            .or_else(|| state.cursor.filter(|cursor| *cursor < rows.len()))
+    }
+
+    /// Returns the bounds of the expand and collapse arrow of a row.
+    fn toggle_bounds(&self, row_bounds: Rectangle, depth: usize) -> Rectangle {
+        Rectangle {
+            x: row_bounds.x + depth as f32 * self.indent,
+            width: self.indent,
+            ..row_bounds
+        }
+    }
+
+    /// Moves the keyboard focus to a row and selects its node.
+    fn focus(
+        &self,
+        state: &mut State,
+        rows: &[Row<'_, '_, Id, Message, Renderer>],
+        index: usize,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        state.cursor = Some(index);
+        if let Some(on_select) = &self.on_select {
+            shell.publish(on_select(rows[index].node.id.clone()));
+        }
+    }
+
+    /// Expands or collapses the node of a row.
+    fn toggle(&self, row: &Row<'_, '_, Id, Message, Renderer>, shell: &mut Shell<'_, Message>) {
+        let on_toggle = if row.node.expanded {
+            &self.on_collapse
+        } else {
+            &self.on_expand
+        };
+
+        if let Some(on_toggle) = on_toggle {
+            shell.publish(on_toggle(row.node.id.clone()));
+        }
+    }
+
+    /// Handles a key press while the tree view is focused.
+    fn on_key_pressed(
+        &self,
+        state: &mut State,
+        rows: &[Row<'_, '_, Id, Message, Renderer>],
+        key_code: KeyCode,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if rows.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        let last = rows.len() - 1;
+        let cursor = self.cursor(state, rows);
+
+        match (key_code, cursor) {
+            (KeyCode::Down, None) | (KeyCode::Home, _) => self.focus(state, rows, 0, shell),
+            (KeyCode::Up, None) | (KeyCode::End, _) => self.focus(state, rows, last, shell),
+            (KeyCode::Down, Some(cursor)) => {
+                self.focus(state, rows, (cursor + 1).min(last), shell);
+            }
+            (KeyCode::Up, Some(cursor)) => {
+                self.focus(state, rows, cursor.saturating_sub(1), shell);
+            }
+            (KeyCode::Right, Some(cursor)) => {
+                let row = &rows[cursor];
+                if !row.node.expanded && row.node.is_expandable() {
+                    self.toggle(row, shell);
+                } else if row.node.expanded && !row.node.children.is_empty() {
+                    self.focus(state, rows, cursor + 1, shell);
+                }
+            }
+            (KeyCode::Left, Some(cursor)) => {
+                let row = &rows[cursor];
+                if row.node.expanded && row.node.is_expandable() {
+                    self.toggle(row, shell);
+                } else if let Some(parent) = row.parent {
+                    self.focus(state, rows, parent, shell);
+                }
+            }
+            (KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space, Some(cursor)) => {
+                self.focus(state, rows, cursor, shell);
+            }
+            (KeyCode::Escape, _) => state.is_focused = false,
+            _ => return event::Status::Ignored,
+        }
+
+        event::Status::Captured
+    }
+}
+
+impl<'a, Id, Message, Renderer> Widget<Message, Renderer> for TreeView<'a, Id, Message, Renderer>
+where
+    Id: Clone + PartialEq,
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn tag(&self) -> Tag {
+        Tag::of::<State>()
+    }
+
+    fn state(&self) -> TreeState {
+        TreeState::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.nodes.iter().map(node_tree).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        diff_nodes(&mut tree.children, &self.nodes);
+    }
+
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max_width = limits.max().width;
+
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+        let rows: Vec<_> = flatten(&self.nodes)
+            .iter()
+            .map(|row| {
+                let x = (row.depth + 1) as f32 * self.indent;
+                let content_limits = Limits::new(
+                    Size::ZERO,
+                    Size::new((max_width - x).max(0.0), f32::INFINITY),
+                );
+                let mut content = row
+                    .node
+                    .content
+                    .as_widget()
+                    .layout(renderer, &content_limits);
+                let content_size = content.size();
+                let height = (content_size.height + 2.0 * self.padding).max(self.indent);
+                content.move_to(Point::new(x, (height - content_size.height) / 2.0));
+
+                let row_y = y;
+                y += height;
+                width = width.max(x + content_size.width);
+                (content, row_y, height)
+            })
+            .collect();
+
+        // The maximum width is infinite inside of a horizontal scrollable, so
+        // the tree view falls back to the width of its contents there.
+        let fill_width = limits.fill().width;
+        if self.width != Length::Shrink && fill_width.is_finite() {
+            width = fill_width;
+        }
+        let size = limits.resolve(Size::new(width, y));
+
+        let rows = rows
+            .into_iter()
+            .map(|(content, row_y, height)| {
+                let mut node = Node::with_children(Size::new(size.width, height), vec![content]);
+                node.move_to(Point::new(0.0, row_y));
+                node
+            })
+            .collect();
+
+        Node::with_children(size, rows)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        let rows = flatten(&self.nodes);
+        let mut trees = Vec::new();
+        content_trees_mut(&self.nodes, &mut tree.children, &mut trees);
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            for ((row, row_tree), row_layout) in rows.iter().zip(&mut trees).zip(layout.children())
+            {
+                if let Some(content_layout) = row_layout.children().next() {
+                    row.node.content.as_widget().operate(
+                        row_tree,
+                        content_layout,
+                        renderer,
+                        operation,
+                    );
+                }
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let mut contents = Vec::new();
+        contents_mut(&mut self.nodes, &mut tree.children, &mut contents);
+
+        let status = contents
+            .into_iter()
+            .zip(layout.children())
+            .filter_map(|((content, row_tree), row_layout)| {
+                row_layout
+                    .children()
+                    .next()
+                    .map(|content_layout| (content, row_tree, content_layout))
+            })
+            .map(|(content, row_tree, content_layout)| {
+                content.as_widget_mut().on_event(
+                    row_tree,
+                    event.clone(),
+                    content_layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge);
+
+        if status == event::Status::Captured {
+            return status;
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+        let rows = flatten(&self.nodes);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    state.is_focused = false;
+                    return event::Status::Ignored;
+                };
+                state.is_focused = true;
+
+                let Some((index, row_layout)) = layout
+                    .children()
+                    .enumerate()
+                    .find(|(_, row_layout)| row_layout.bounds().contains(position))
+                else {
+                    return event::Status::Captured;
+                };
+
+                let row = &rows[index];
+                if row.node.is_expandable()
+                    && self
+                        .toggle_bounds(row_layout.bounds(), row.depth)
+                        .contains(position)
+                {
+                    state.cursor = Some(index);
+                    self.toggle(row, shell);
+                } else {
+                    self.focus(state, &rows, index, shell);
+                }
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed { key_code, .. }) if state.is_focused => {
+                self.on_key_pressed(state, &rows, key_code, shell)
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let mut contents = Vec::new();
+        contents_mut(&mut self.nodes, &mut tree.children, &mut contents);
+
+        let overlays = contents
+            .into_iter()
+            .zip(layout.children())
+            .filter_map(|((content, row_tree), row_layout)| {
+                let content_layout = row_layout.children().next()?;
+                content
+                    .as_widget_mut()
+                    .overlay(row_tree, content_layout, renderer)
+            })
+            .collect::<Vec<_>>();
+
+        (!overlays.is_empty()).then(|| overlay::Group::with_children(overlays).overlay())
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let rows = flatten(&self.nodes);
+        let mut trees = Vec::new();
+        content_trees(&self.nodes, &tree.children, &mut trees);
+
+        for ((row, row_tree), row_layout) in rows.iter().zip(trees).zip(layout.children()) {
+            if !cursor.is_over(row_layout.bounds()) {
+                continue;
+            }
+
+            let interaction = row_layout.children().next().map_or_else(
+                mouse::Interaction::default,
+                |content_layout| {
+                    row.node.content.as_widget().mouse_interaction(
+                        row_tree,
+                        content_layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                    )
+                },
+            );
+
+            return if interaction == mouse::Interaction::default() {
+                mouse::Interaction::Pointer
+            } else {
+                interaction
+            };
+        }
+
+        mouse::Interaction::default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let appearance = theme.active(self.style);
+        let rows = flatten(&self.nodes);
+        let mut trees = Vec::new();
+        content_trees(&self.nodes, &tree.children, &mut trees);
+        let focused_row = if state.is_focused {
+            self.cursor(state, &rows)
+        } else {
+            None
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius.into(),
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance.background,
+        );
+
+        for (index, ((row, row_tree), row_layout)) in
+            rows.iter().zip(trees).zip(layout.children()).enumerate()
+        {
+            let row_bounds = row_layout.bounds();
+            if row_bounds.intersection(viewport).is_none() {
+                continue;
+            }
+
+            let is_selected = self
+                .selected
+                .as_ref()
+                .is_some_and(|selected| row.node.id == *selected);
+
+            if is_selected || cursor.is_over(row_bounds) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    if is_selected {
+                        appearance.selected_background
+                    } else {
+                        appearance.hovered_background
+                    },
+                );
+            }
+
+            if focused_row == Some(index) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: row_bounds,
+                        border_radius: (0.0).into(),
+                        border_width: 1.0,
+                        border_color: appearance.focus_color,
+                    },
+                    Color::TRANSPARENT,
+                );
+            }
+
+            for level in 0..row.depth {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: (row_bounds.x + (level as f32 + 0.5) * self.indent).floor(),
+                            width: 1.0,
+                            ..row_bounds
+                        },
+                        border_radius: (0.0).into(),
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    appearance.guide_color,
+                );
+            }
+
+            if row.node.is_expandable() {
+                let toggle_bounds = self.toggle_bounds(row_bounds, row.depth);
+                let icon = if row.node.expanded {
+                    Icon::CaretDownFill
+                } else {
+                    Icon::CaretRightFill
+                };
+
+                renderer.fill_text(core::text::Text {
+                    content: &char::from(icon).to_string(),
+                    bounds: Rectangle {
+                        x: toggle_bounds.center_x(),
+                        y: toggle_bounds.center_y(),
+                        ..toggle_bounds
+                    },
+                    size: self.indent * 0.6,
+                    color: appearance.toggle_color,
+                    font: ICON_FONT,
+                    horizontal_alignment: Horizontal::Center,
+                    vertical_alignment: Vertical::Center,
+                    line_height: LineHeight::default(),
+                    shaping: iced_widget::text::Shaping::Basic,
+                });
+            }
+
+            if let Some(content_layout) = row_layout.children().next() {
+                row.node.content.as_widget().draw(
+                    row_tree,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: if is_selected {
+                            appearance.selected_text_color
+                        } else {
+                            appearance.text_color
+                        },
+                    },
+                    content_layout,
+                    cursor,
+                    viewport,
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Id, Message, Renderer> From<TreeView<'a, Id, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Id: 'a + Clone + PartialEq,
+    Message: 'a,
+    Renderer: 'a + core::Renderer + core::text::Renderer<Font = core::Font>,
+    Renderer::Theme: StyleSheet,
+{
+    fn from(tree_view: TreeView<'a, Id, Message, Renderer>) -> Self {
+        Element::new(tree_view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{content_trees, content_trees_mut, diff_nodes, flatten, node_tree, TreeNode};
+    use iced_widget::Space;
+
+    fn node(id: usize) -> TreeNode<'static, usize, (), crate::Renderer> {
+        TreeNode::new(id, Space::with_width(0))
+    }
+
+    #[test]
+    fn flatten_skips_collapsed_children() {
+        let nodes = vec![
+            node(0)
+                .push(node(1).push(node(2)).expanded(false))
+                .push(node(3))
+                .expanded(true),
+            node(4).push(node(5)),
+        ];
+
+        let rows = flatten(&nodes);
+        let ids: Vec<_> = rows.iter().map(|row| row.node.id).collect();
+        let depths: Vec<_> = rows.iter().map(|row| row.depth).collect();
+        let parents: Vec<_> = rows.iter().map(|row| row.parent).collect();
+
+        assert_eq!(ids, vec![0, 1, 3, 4]);
+        assert_eq!(depths, vec![0, 1, 1, 0]);
+        assert_eq!(parents, vec![None, Some(0), Some(0), None]);
+    }
+
+    #[test]
+    fn lazy_nodes_are_expandable() {
+        assert!(!node(0).is_expandable());
+        assert!(node(0).has_children(true).is_expandable());
+        assert!(node(0).push(node(1)).is_expandable());
+    }
+
+    #[test]
+    fn trees_follow_the_hierarchy() {
+        let mut nodes = vec![node(0).push(node(1)).push(node(2)).expanded(true), node(3)];
+        let mut trees: Vec<_> = nodes.iter().map(node_tree).collect();
+
+        let mut visible = Vec::new();
+        content_trees(&nodes, &trees, &mut visible);
+        assert_eq!(visible.len(), 4);
+
+        let mut visible_mut = Vec::new();
+        content_trees_mut(&nodes, &mut trees, &mut visible_mut);
+        assert_eq!(visible_mut.len(), 4);
+
+        // Collapsing a node keeps the trees of its children and siblings.
+        nodes[0].expanded = false;
+        diff_nodes(&mut trees, &nodes);
+        assert_eq!(trees.len(), 2);
+        assert_eq!(trees[0].children[1].children.len(), 2);
+
+        let mut visible = Vec::new();
+        content_trees(&nodes, &trees, &mut visible);
+        assert_eq!(visible.len(), 2);
+        assert!(std::ptr::eq(
+            std::ptr::from_ref(visible[1]),
+            std::ptr::from_ref(&trees[1].children[0])
+        ));
+    }
+}
@@ -0,0 +1,51 @@
+//! Search and select a value from a filtered list of options.
+//!
+//! *This API requires the following crate features to be activated: `combo_box`*
+
+use iced_widget::{core::Color, style::Theme};
+
+/// The appearance of the matches in the list of a [`ComboBox`](crate::native::combo_box::ComboBox).
+///
+/// The list itself is styled like a [`SelectionList`](crate::native::selection_list::SelectionList).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The color of the characters matching the search query.
+    pub highlight_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            highlight_color: Color::from_rgb(0.0, 0.4, 0.9),
+        }
+    }
+}
+
+/// The appearance of a [`ComboBox`](crate::native::combo_box::ComboBox).
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub trait StyleSheet {
+    type Style: Default + Copy;
+    /// The normal appearance of the matches of a [`ComboBox`](crate::native::combo_box::ComboBox).
+    fn active(&self, style: Self::Style) -> Appearance;
+}
+
+/// The default appearance of the [`ComboBox`](crate::native::combo_box::ComboBox).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+/// Default Prebuilt ``ComboBox`` Styles
+pub enum ComboBoxStyles {
+    #[default]
+    Default,
+}
+
+impl StyleSheet for Theme {
+    type Style = ComboBoxStyles;
+
+    fn active(&self, _style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        Appearance {
+            highlight_color: palette.primary.strong.color,
+        }
+    }
+}
@@ -14,8 +14,19 @@ pub struct Appearance {
     pub border_color: Color,
     /// The expand value of the menus' background
     pub background_expand: [u16; 4],
-    /// The highlighted path [`Color`] of the the menu bar and its menus.
+    /// The highlighted path [`Color`] of the the menu bar and its menus,
+    /// also used as the background of hovered built-in menu items.
     pub path: Color,
+    /// The border [`Color`] of the item with keyboard focus.
+    pub keyboard_focus: Color,
+    /// The [`Color`] of the shortcut labels of the items.
+    pub shortcut: Color,
+    /// The text [`Color`] of the built-in menu items.
+    pub text_color: Color,
+    /// The text [`Color`] of disabled built-in menu items.
+    pub disabled_text_color: Color,
+    /// The [`Color`] of separator items.
+    pub separator: Color,
 }
 impl std::default::Default for Appearance {
     fn default() -> Self {
@@ -26,6 +37,11 @@ impl std::default::Default for Appearance {
             border_color: Color::from([0.5; 3]),
             background_expand: [6; 4],
             path: Color::from([0.3; 3]),
+            keyboard_focus: Color::from_rgb(0.0, 0.4, 0.9),
+            shortcut: Color::from([0.4; 3]),
+            text_color: Color::BLACK,
+            disabled_text_color: Color::from([0.6; 3]),
+            separator: Color::from([0.5; 3]),
         }
     }
 }
@@ -77,6 +93,11 @@ impl StyleSheet for Theme {
                 border_color: palette.background.weak.color,
                 background_expand: [6; 4],
                 path: palette.primary.weak.color,
+                keyboard_focus: palette.primary.strong.color,
+                shortcut: palette.background.strong.color,
+                text_color: palette.background.base.text,
+                disabled_text_color: palette.background.strong.color,
+                separator: palette.background.strong.color,
             },
             MenuBarStyle::Custom(c) => c.appearance(self),
         }
@@ -37,6 +37,21 @@ pub mod number_input;
 #[cfg(feature = "number_input")]
 pub use number_input::NumberInputStyles;
 
+#[cfg(feature = "range_slider")]
+pub mod range_slider;
+#[cfg(feature = "range_slider")]
+pub use range_slider::RangeSliderStyles;
+
+#[cfg(feature = "combo_box")]
+pub mod combo_box;
+#[cfg(feature = "combo_box")]
+pub use combo_box::ComboBoxStyles;
+
+#[cfg(feature = "tree_view")]
+pub mod tree_view;
+#[cfg(feature = "tree_view")]
+pub use tree_view::TreeViewStyles;
+
 #[cfg(feature = "selection_list")]
 pub mod selection_list;
 #[cfg(feature = "selection_list")]
@@ -47,6 +62,11 @@ pub mod split;
 #[cfg(feature = "split")]
 pub use split::SplitStyles;
 
+#[cfg(feature = "table")]
+pub mod table;
+#[cfg(feature = "table")]
+pub use table::TableStyles;
+
 #[cfg(feature = "menu")]
 pub mod menu_bar;
 #[cfg(feature = "menu")]
@@ -0,0 +1,119 @@
+//! Use a range slider to select a range of values between two bounds.
+//!
+//! *This API requires the following crate features to be activated: `range_slider`*
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the rail outside of the selected range.
+    pub rail_background: Background,
+    /// The background of the rail between the two handles.
+    pub range_background: Background,
+    /// The thickness of the rail.
+    pub rail_width: f32,
+    /// The border radius of the rail.
+    pub rail_border_radius: f32,
+    /// The background of the handles.
+    pub handle_background: Background,
+    /// The radius of the handles.
+    pub handle_radius: f32,
+    /// The border width of the handles.
+    pub handle_border_width: f32,
+    /// The border color of the handles.
+    pub handle_border_color: Color,
+    /// The border color of the handle that has the keyboard focus.
+    pub focused_handle_border_color: Color,
+    /// The color of the tick marks.
+    pub tick_color: Color,
+    /// The color of the value labels.
+    pub label_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            rail_background: Color::from_rgb(0.8, 0.8, 0.8).into(),
+            range_background: Color::from_rgb(0.3, 0.5, 0.9).into(),
+            rail_width: 4.0,
+            rail_border_radius: 2.0,
+            handle_background: Color::WHITE.into(),
+            handle_radius: 8.0,
+            handle_border_width: 1.0,
+            handle_border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            focused_handle_border_color: Color::from_rgb(0.3, 0.5, 0.9),
+            tick_color: Color::from_rgb(0.6, 0.6, 0.6),
+            label_color: Color::BLACK,
+        }
+    }
+}
+
+/// The appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider).
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub trait StyleSheet {
+    type Style: Default + Copy;
+    /// The normal appearance of a [`RangeSlider`](crate::native::range_slider::RangeSlider).
+    fn active(&self, style: Self::Style) -> Appearance;
+
+    /// The appearance when the [`RangeSlider`](crate::native::range_slider::RangeSlider) is hovered.
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        self.active(style)
+    }
+
+    /// The appearance when a handle of the [`RangeSlider`](crate::native::range_slider::RangeSlider) is dragged.
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        self.hovered(style)
+    }
+}
+
+/// The default appearance of the [`RangeSlider`](crate::native::range_slider::RangeSlider).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+/// Default Prebuilt ``RangeSlider`` Styles
+pub enum RangeSliderStyles {
+    #[default]
+    Default,
+}
+
+impl StyleSheet for Theme {
+    type Style = RangeSliderStyles;
+
+    fn active(&self, _style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        Appearance {
+            rail_background: palette.background.strong.color.into(),
+            range_background: palette.primary.base.color.into(),
+            handle_background: palette.primary.base.color.into(),
+            handle_border_color: palette.primary.strong.color,
+            focused_handle_border_color: palette.primary.strong.text,
+            tick_color: palette.background.strong.color,
+            label_color: palette.background.base.text,
+            ..Appearance::default()
+        }
+    }
+
+    fn hovered(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            handle_background: palette.primary.weak.color.into(),
+            ..active
+        }
+    }
+
+    fn dragged(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+        let active = self.active(style);
+
+        Appearance {
+            handle_background: palette.primary.strong.color.into(),
+            ..active
+        }
+    }
+}
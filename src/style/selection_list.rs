@@ -26,6 +26,16 @@ pub struct Appearance {
     pub selected_text_color: Color,
     /// The List Label Text Select Background Color
     pub selected_background: Background,
+    /// Draw a checkbox in front of every option showing whether it is selected
+    pub checkbox: bool,
+    /// The checkbox Background
+    pub checkbox_background: Background,
+    /// The checkbox Border color
+    pub checkbox_border_color: Color,
+    /// The checkbox checkmark Color
+    pub checkmark_color: Color,
+    /// The Color of the line showing where a dragged option is dropped
+    pub drop_indicator_color: Color,
 }
 
 impl std::default::Default for Appearance {
@@ -39,6 +49,11 @@ impl std::default::Default for Appearance {
             hovered_background: Background::Color([0.0, 0.5, 1.0].into()),
             selected_text_color: Color::WHITE,
             selected_background: Background::Color([0.2, 0.5, 0.8].into()),
+            checkbox: false,
+            checkbox_background: Background::Color(Color::WHITE),
+            checkbox_border_color: [0.7, 0.7, 0.7].into(),
+            checkmark_color: [0.2, 0.5, 0.8].into(),
+            drop_indicator_color: [0.2, 0.5, 0.8].into(),
         }
     }
 }
@@ -57,15 +72,16 @@ pub trait StyleSheet {
 pub enum SelectionListStyles {
     #[default]
     Default,
+    Checkbox,
 }
 
 impl StyleSheet for Theme {
     type Style = SelectionListStyles;
-    fn style(&self, _style: Self::Style) -> Appearance {
+    fn style(&self, style: Self::Style) -> Appearance {
         let palette = self.extended_palette();
         let foreground = self.palette();
 
-        Appearance {
+        let appearance = Appearance {
             text_color: foreground.text,
             background: palette.background.base.color.into(),
             border_color: foreground.text,
@@ -73,7 +89,23 @@ impl StyleSheet for Theme {
             hovered_background: palette.primary.weak.color.into(),
             selected_text_color: palette.primary.strong.text,
             selected_background: palette.primary.strong.color.into(),
+            checkbox_background: palette.background.base.color.into(),
+            checkbox_border_color: palette.background.strong.color,
+            checkmark_color: palette.primary.strong.color,
+            drop_indicator_color: palette.primary.strong.color,
             ..Appearance::default()
+        };
+
+        match style {
+            SelectionListStyles::Default => appearance,
+            SelectionListStyles::Checkbox => Appearance {
+                checkbox: true,
+                selected_text_color: palette.primary.weak.text,
+                selected_background: appearance.hovered_background,
+                hovered_text_color: palette.background.weak.text,
+                hovered_background: palette.background.weak.color.into(),
+                ..appearance
+            },
         }
     }
 }
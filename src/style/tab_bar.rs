@@ -37,6 +37,13 @@ pub struct Appearance {
 
     /// The text color of the tab labels.
     pub text_color: Color,
+
+    /// The color of the line showing where a dragged tab is dropped.
+    pub drop_indicator_color: Color,
+
+    /// The color of the outline of the active tab while the tab bar has
+    /// keyboard focus.
+    pub focus_color: Color,
 }
 
 /// The appearance of a [`TabBar`](crate::native::tab_bar::TabBar).
@@ -66,6 +73,8 @@ impl Default for Appearance {
             tab_label_border_width: 1.0,
             icon_color: Color::BLACK,
             text_color: Color::BLACK,
+            drop_indicator_color: [0.2, 0.5, 0.8].into(),
+            focus_color: [0.2, 0.5, 0.8].into(),
         }
     }
 }
@@ -119,6 +128,8 @@ impl StyleSheet for Theme {
                 appearance.tab_label_border_color = [0.3, 0.3, 0.3].into();
                 appearance.icon_color = Color::WHITE;
                 appearance.text_color = Color::WHITE;
+                appearance.drop_indicator_color = Color::WHITE;
+                appearance.focus_color = Color::WHITE;
             }
             TabBarStyles::Red => {
                 let text_color = if is_active {
@@ -136,6 +147,8 @@ impl StyleSheet for Theme {
                 appearance.tab_label_border_color = Color::TRANSPARENT;
                 appearance.icon_color = text_color;
                 appearance.text_color = text_color;
+                appearance.drop_indicator_color = [1.0, 0.0, 0.0].into();
+                appearance.focus_color = [1.0, 0.0, 0.0].into();
             }
             TabBarStyles::Blue => {
                 appearance.tab_label_background = if is_active {
@@ -150,6 +163,8 @@ impl StyleSheet for Theme {
                 };
                 appearance.icon_color = Color::WHITE;
                 appearance.text_color = Color::WHITE;
+                appearance.drop_indicator_color = [0.0, 0.0, 1.0].into();
+                appearance.focus_color = [0.0, 0.0, 1.0].into();
             }
             TabBarStyles::Green => {
                 let color = if is_active {
@@ -163,6 +178,8 @@ impl StyleSheet for Theme {
                 appearance.tab_label_background = Color::WHITE.into();
                 appearance.icon_color = color;
                 appearance.text_color = color;
+                appearance.drop_indicator_color = [0.0, 0.5, 0.0].into();
+                appearance.focus_color = [0.0, 0.5, 0.0].into();
             }
             TabBarStyles::Purple => {
                 let text_color = if is_active {
@@ -176,6 +193,8 @@ impl StyleSheet for Theme {
                 appearance.tab_label_border_width = 0.0;
                 appearance.icon_color = text_color;
                 appearance.text_color = text_color;
+                appearance.drop_indicator_color = [0.7, 0.0, 1.0].into();
+                appearance.focus_color = [0.7, 0.0, 1.0].into();
             }
         }
 
@@ -0,0 +1,131 @@
+//! Display tabular data with a header row, sortable and resizable columns.
+//!
+//! *This API requires the following crate features to be activated: `table`*
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`Table`](crate::native::table::Table).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The optional background of the [`Table`](crate::native::table::Table).
+    pub background: Option<Background>,
+
+    /// The border width of the [`Table`](crate::native::table::Table).
+    pub border_width: f32,
+
+    /// The border color of the [`Table`](crate::native::table::Table).
+    pub border_color: Color,
+
+    /// The background of the header row of the [`Table`](crate::native::table::Table).
+    pub header_background: Background,
+
+    /// The text color of the header row of the [`Table`](crate::native::table::Table).
+    pub header_text_color: Color,
+
+    /// The color of the sort indicator of the [`Table`](crate::native::table::Table).
+    pub sort_indicator_color: Color,
+
+    /// The color of the column dividers of the [`Table`](crate::native::table::Table).
+    pub divider_color: Color,
+
+    /// The color of a hovered or dragged column divider of the [`Table`](crate::native::table::Table).
+    pub divider_hovered_color: Color,
+
+    /// The optional background of the rows of the [`Table`](crate::native::table::Table).
+    pub row_background: Option<Background>,
+
+    /// The optional background of every second row of the [`Table`](crate::native::table::Table).
+    pub striped_row_background: Option<Background>,
+
+    /// The optional background of the hovered row of the [`Table`](crate::native::table::Table).
+    pub hovered_row_background: Option<Background>,
+
+    /// The background of the selected row of the [`Table`](crate::native::table::Table).
+    pub selected_row_background: Background,
+
+    /// The default text color of the rows of the [`Table`](crate::native::table::Table).
+    pub text_color: Color,
+
+    /// The default text color of the selected row of the [`Table`](crate::native::table::Table).
+    pub selected_text_color: Color,
+}
+
+/// The appearance of a [`Table`](crate::native::table::Table).
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub trait StyleSheet {
+    type Style: Default + Copy;
+    /// The normal appearance of a [`Table`](crate::native::table::Table).
+    fn active(&self, style: Self::Style) -> Appearance;
+}
+
+/// The default appearance of the [`Table`](crate::native::table::Table).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+/// Default Prebuilt ``Table`` Styles
+pub enum TableStyles {
+    #[default]
+    Default,
+    Plain,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border_width: 1.0,
+            border_color: Color::from_rgb(0.8, 0.8, 0.8),
+            header_background: Color::from_rgb(0.9, 0.9, 0.9).into(),
+            header_text_color: Color::BLACK,
+            sort_indicator_color: Color::BLACK,
+            divider_color: Color::from_rgb(0.8, 0.8, 0.8),
+            divider_hovered_color: Color::from_rgb(0.5, 0.5, 0.5),
+            row_background: None,
+            striped_row_background: Some(Color::from_rgb(0.96, 0.96, 0.96).into()),
+            hovered_row_background: Some(Color::from_rgb(0.92, 0.92, 0.92).into()),
+            selected_row_background: Color::from_rgb(0.3, 0.5, 0.9).into(),
+            text_color: Color::BLACK,
+            selected_text_color: Color::WHITE,
+        }
+    }
+}
+
+impl StyleSheet for Theme {
+    type Style = TableStyles;
+
+    fn active(&self, style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        let appearance = Appearance {
+            background: Some(palette.background.base.color.into()),
+            border_color: palette.background.strong.color,
+            header_background: palette.background.weak.color.into(),
+            header_text_color: palette.background.weak.text,
+            sort_indicator_color: palette.primary.base.color,
+            divider_color: palette.background.strong.color,
+            divider_hovered_color: palette.primary.base.color,
+            striped_row_background: Some(
+                Color {
+                    a: 0.5,
+                    ..palette.background.weak.color
+                }
+                .into(),
+            ),
+            hovered_row_background: Some(palette.background.weak.color.into()),
+            selected_row_background: palette.primary.weak.color.into(),
+            text_color: palette.background.base.text,
+            selected_text_color: palette.primary.weak.text,
+            ..Appearance::default()
+        };
+
+        match style {
+            TableStyles::Default => appearance,
+            TableStyles::Plain => Appearance {
+                striped_row_background: None,
+                ..appearance
+            },
+        }
+    }
+}
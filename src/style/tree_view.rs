@@ -0,0 +1,91 @@
+//! Display hierarchical data as a tree of expandable nodes.
+//!
+//! *This API requires the following crate features to be activated: `tree_view`*
+
+use iced_widget::{
+    core::{Background, Color},
+    style::Theme,
+};
+
+/// The appearance of a [`TreeView`](crate::native::tree_view::TreeView).
+#[derive(Clone, Copy, Debug)]
+pub struct Appearance {
+    /// The background of the tree view.
+    pub background: Background,
+    /// The border width of the tree view.
+    pub border_width: f32,
+    /// The border color of the tree view.
+    pub border_color: Color,
+    /// The border radius of the tree view.
+    pub border_radius: f32,
+    /// The text color of the nodes.
+    pub text_color: Color,
+    /// The background of the hovered node.
+    pub hovered_background: Background,
+    /// The text color of the selected node.
+    pub selected_text_color: Color,
+    /// The background of the selected node.
+    pub selected_background: Background,
+    /// The color of the expand and collapse arrows.
+    pub toggle_color: Color,
+    /// The color of the indentation guides.
+    pub guide_color: Color,
+    /// The border color of the node with keyboard focus.
+    pub focus_color: Color,
+}
+
+impl Default for Appearance {
+    fn default() -> Self {
+        Self {
+            background: Color::WHITE.into(),
+            border_width: 1.0,
+            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            border_radius: 0.0,
+            text_color: Color::BLACK,
+            hovered_background: Color::from_rgb(0.9, 0.9, 0.9).into(),
+            selected_text_color: Color::WHITE,
+            selected_background: Color::from_rgb(0.2, 0.5, 0.8).into(),
+            toggle_color: Color::from_rgb(0.4, 0.4, 0.4),
+            guide_color: Color::from_rgb(0.85, 0.85, 0.85),
+            focus_color: Color::from_rgb(0.0, 0.4, 0.9),
+        }
+    }
+}
+
+/// The appearance of a [`TreeView`](crate::native::tree_view::TreeView).
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+pub trait StyleSheet {
+    type Style: Default + Copy;
+    /// The normal appearance of a [`TreeView`](crate::native::tree_view::TreeView).
+    fn active(&self, style: Self::Style) -> Appearance;
+}
+
+/// The default appearance of the [`TreeView`](crate::native::tree_view::TreeView).
+#[derive(Clone, Copy, Debug, Default)]
+#[allow(missing_docs, clippy::missing_docs_in_private_items)]
+/// Default Prebuilt ``TreeView`` Styles
+pub enum TreeViewStyles {
+    #[default]
+    Default,
+}
+
+impl StyleSheet for Theme {
+    type Style = TreeViewStyles;
+
+    fn active(&self, _style: Self::Style) -> Appearance {
+        let palette = self.extended_palette();
+
+        Appearance {
+            background: palette.background.base.color.into(),
+            border_color: palette.background.strong.color,
+            text_color: palette.background.base.text,
+            hovered_background: palette.background.weak.color.into(),
+            selected_text_color: palette.primary.strong.text,
+            selected_background: palette.primary.strong.color.into(),
+            toggle_color: palette.background.strong.text,
+            guide_color: palette.background.strong.color,
+            focus_color: palette.primary.base.color,
+            ..Appearance::default()
+        }
+    }
+}